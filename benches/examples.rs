@@ -74,12 +74,75 @@ fn bench_ip_example(c: &mut Criterion) {
     });
 }
 
+fn bench_take_until(c: &mut Criterion) {
+    use munch::Parser;
+
+    let line = "2024-01-01T00:00:00Z INFO server: accepted connection from 192.0.2.62:143\n";
+    let log = line.repeat(1000);
+
+    let mut group = c.benchmark_group("take until");
+
+    group.throughput(Throughput::Bytes(log.len() as u64));
+
+    fn lines<P>(mut line: P, log: &[u8]) -> usize
+    where
+        P: for<'a> Parser<&'a [u8], Output = &'a [u8], Error = munch::error::Error<'static>>,
+    {
+        let (mut from, mut count) = (0, 0);
+        while from < log.len() {
+            let (to, _) = line.parse(log, from).unwrap();
+            from = to + 1;
+            count += 1;
+        }
+        count
+    }
+
+    group.bench_function("byte::TakeWhile", |b| {
+        b.iter(|| {
+            lines(
+                munch::byte::TakeWhile(|b| b != b'\n'),
+                black_box(log.as_bytes()),
+            )
+        });
+    });
+
+    group.bench_function("byte::TakeUntil", |b| {
+        b.iter(|| lines(munch::byte::TakeUntil(b'\n'), black_box(log.as_bytes())));
+    });
+
+    group.bench_function("byte::TakeUntilAny", |b| {
+        b.iter(|| {
+            lines(
+                munch::byte::TakeUntilAny([b'\r', b'\n']),
+                black_box(log.as_bytes()),
+            )
+        });
+    });
+
+    group.bench_function("str::TakeWhile", |b| {
+        b.iter(|| {
+            munch::str::TakeWhile(|ch| ch != '\n')
+                .parse(black_box(log.as_str()), 0)
+                .unwrap()
+        });
+    });
+
+    group.bench_function("str::TakeUntil", |b| {
+        b.iter(|| {
+            munch::str::TakeUntil('\n')
+                .parse(black_box(log.as_str()), 0)
+                .unwrap()
+        });
+    });
+}
+
 criterion::criterion_group!(
     benches,
     bench_arithmetic_example,
     bench_clojure_example,
     bench_ini_example,
     bench_ip_example,
+    bench_take_until,
 );
 
 criterion::criterion_main!(benches);
@@ -1,14 +1,14 @@
-use munch::{Optional, Parser};
+use munch::Parser;
 
 pub fn expr(str: &str, from: usize) -> munch::Result<i64, munch::error::Error<'static>> {
+    use munch::num;
     use munch::str::*;
 
     let ws = TakeWhile(char::is_whitespace);
 
-    let integer =
-        Capture((Optional('-'), TakeWhile1(|ch| ch.is_digit(10)))).map(|str| str.parse().unwrap());
+    let integer = num::i64();
 
-    let factor = ('('.p() >> ws >> expr << ws << ')' | integer) << ws;
+    let factor = ('('.p() >> ws >> expr << ws << ')').alt(integer) << ws;
 
     let term = factor.repeat(1..).join('*'.or('/') << ws).fold(
         || 0,
@@ -61,7 +61,10 @@ pub fn main() {
         print!("> ");
         stdout.flush().unwrap();
         stdin.read_line(&mut string).unwrap();
-        println!("{:?}", parse(&string));
+        match parse(&string) {
+            Ok(output) => println!("{:?}", output),
+            Err((from, error)) => println!("{}", munch::report::Report::new(&string, from, &error)),
+        }
         string.clear();
     }
 }
@@ -1,4 +1,4 @@
-use munch::{Optional, Parser, Try};
+use munch::{Lookahead, Optional, Parser, Try};
 
 pub static EXAMPLE: &str = "
 (defn sum [xs]
@@ -19,17 +19,18 @@ pub enum Value<'a> {
     Vector(Vec<Value<'a>>),
 }
 
-pub fn value(str: &str, from: usize) -> munch::Result<Value, munch::error::Error<'static>> {
-    use munch::ascii;
+pub fn value(str: &str, from: usize) -> munch::Result<Value<'_>, munch::error::Error<'static>> {
+    use munch::num;
     use munch::str::*;
 
     let ws = TakeWhile(char::is_whitespace);
 
-    let integer = Capture(Try((
-        Optional('-'.or('+')),
-        ascii::TakeWhile1(|b| b >= b'0' && b <= b'9'),
-    )))
-    .map(|str| Value::Integer(str.parse().unwrap()));
+    // A `+` only belongs to an integer if a digit follows it; `+-5` is a symbol. Nothing else
+    // backtracks, so an integer that overflows is reported as such.
+    let plus = Try('+'.p() << Lookahead(Satisfy(|ch: char| ch.is_ascii_digit())));
+    let integer = (Optional(plus).p() >> num::i64())
+        .map(Value::Integer)
+        .label("integer");
 
     let is_symbol_head = |ch| {
         matches!(ch,
@@ -50,15 +51,17 @@ pub fn value(str: &str, from: usize) -> munch::Result<Value, munch::error::Error
         | '>')
     };
     let is_symbol_tail = |ch| is_symbol_head(ch) || matches!(ch, '0'..='9' | ':' | '#');
-    let symbol = Capture((Satisfy(&is_symbol_head), TakeWhile(is_symbol_tail))).map(Value::Symbol);
+    let symbol = Capture((Satisfy(&is_symbol_head), TakeWhile(is_symbol_tail)))
+        .map(Value::Symbol)
+        .label("symbol");
 
     let list = '('.p() >> ws >> value.repeat(..).map(Value::List) << ')';
     let vector = '['.p() >> ws >> value.repeat(..).map(Value::Vector) << ']';
 
-    ((integer | symbol | list | vector) << ws).parse(str, from)
+    (integer.alt(symbol).alt(list).alt(vector) << ws).parse(str, from)
 }
 
-pub fn parse(str: &str) -> Result<Vec<Value>, (usize, munch::error::Error<'static>)> {
+pub fn parse(str: &str) -> Result<Vec<Value<'_>>, (usize, munch::error::Error<'static>)> {
     use munch::str::*;
     match (TakeWhile(char::is_whitespace).p() >> value.repeat(..) << End).parse(str, 0) {
         Ok((_, output)) => Ok(output),
@@ -124,7 +127,10 @@ pub fn main() {
         print!("> ");
         stdout.flush().unwrap();
         stdin.read_line(&mut string).unwrap();
-        println!("{:?}", parse(&string));
+        match parse(&string) {
+            Ok(output) => println!("{:?}", output),
+            Err((from, error)) => println!("{}", munch::report::Report::new(&string, from, &error)),
+        }
         string.clear();
     }
 }
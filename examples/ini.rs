@@ -4,7 +4,7 @@ use munch::{Optional, Parser};
 
 pub type Value<'a> = HashMap<&'a str, HashMap<&'a str, &'a str>>;
 
-pub fn parse(str: &str) -> Result<Value, (usize, munch::error::Error<'static>)> {
+pub fn parse(str: &str) -> Result<Value<'_>, (usize, munch::error::Error<'static>)> {
     use munch::str::*;
 
     let s = || TakeWhile(|ch| ch == ' ' || ch == '\t');
@@ -12,18 +12,24 @@ pub fn parse(str: &str) -> Result<Value, (usize, munch::error::Error<'static>)>
 
     let header = '['.p() >> TakeWhile1(|ch| ch != ']') << ']' << ws;
 
-    let comment = ';'.p() >> TakeWhile(|ch| ch != '\n') << ws;
+    let comment = ';'.p() >> TakeUntil('\n') << ws;
 
     let key = TakeWhile1(char::is_alphanumeric);
-    let value = TakeWhile(|ch| ch != '\n' && ch != ';');
-
-    let kv = (
-        key.p() << s() << '=' << s(),
-        value.p() << s() << Optional(comment) << ws,
-    );
-    let kvs = kv.repeat(..).collect();
-
-    let section = (header, kvs);
+    let value = TakeUntilAny(['\n', ';']);
+
+    // Name the frames after the key and section being parsed, e.g. "in key 'port'".
+    let kv = key.p().bind(move |name| {
+        ((s().p() << '=' << s()) >> value << s() << Optional(comment) << ws)
+            .map(move |value| (name, value))
+            .context_with(move || format!("key '{}'", name))
+    });
+
+    let section = header.bind(move |name| {
+        kv.repeat(..)
+            .collect()
+            .map(move |kvs| (name, kvs))
+            .context_with(move || format!("section [{}]", name))
+    });
     let sections = section.repeat(..).collect();
 
     (ws.p() >> sections << End)
@@ -15,7 +15,7 @@ pub fn parse(str: &str) -> Result<Ip, (usize, Error)> {
     use munch::byte::*;
 
     let octet = || {
-        Satisfy(|b| b'0' <= b && b <= b'9')
+        Satisfy(|b| b.is_ascii_digit())
             .repeat(1..3)
             .fold(|| 0, |acc, x| acc * 10 + x as u16 - 48)
             .map_err(|_| Error::ExpectedInteger)
@@ -0,0 +1,269 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    parse_quote, Attribute, Data, DeriveInput, Error, Expr, Fields, GenericParam, Ident, Lifetime,
+    Result, Type,
+};
+
+#[derive(Copy, Clone)]
+enum Endianness {
+    Little,
+    Big,
+}
+
+enum Len {
+    Prefix(Ident),
+    Field(Ident),
+}
+
+struct Attrs {
+    endianness: Option<Endianness>,
+    len: Option<Len>,
+}
+
+impl Attrs {
+    fn parse(attrs: &[Attribute], field: bool) -> Result<Attrs> {
+        let mut out = Attrs {
+            endianness: None,
+            len: None,
+        };
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("munch")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("little") {
+                    out.endianness = Some(Endianness::Little);
+                } else if meta.path.is_ident("big") {
+                    out.endianness = Some(Endianness::Big);
+                } else if field && meta.path.is_ident("len") {
+                    let ident: Ident = meta.value()?.parse()?;
+                    out.len = Some(if primitive(&ident) {
+                        Len::Prefix(ident)
+                    } else {
+                        Len::Field(ident)
+                    });
+                } else {
+                    return Err(meta.error("unsupported munch attribute"));
+                }
+                Ok(())
+            })?;
+        }
+        Ok(out)
+    }
+}
+
+fn primitive(ident: &Ident) -> bool {
+    [
+        "u8", "u16", "u32", "u64", "i8", "i16", "i32", "i64", "f32", "f64",
+    ]
+    .iter()
+    .any(|ty| ident == ty)
+}
+
+struct Gen {
+    lifetime: Lifetime,
+}
+
+impl Gen {
+    fn input(&self) -> TokenStream {
+        let lifetime = &self.lifetime;
+        quote!(&#lifetime [u8])
+    }
+
+    fn read(&self, ident: &Ident, endianness: Endianness) -> TokenStream {
+        match endianness {
+            Endianness::Little => quote!(::munch::byte::LittleEndian::#ident),
+            Endianness::Big => quote!(::munch::byte::BigEndian::#ident),
+        }
+    }
+
+    fn parser(&self, ty: &Type, endianness: Endianness) -> TokenStream {
+        let input = self.input();
+        match ty {
+            Type::Path(path) if path.qself.is_none() => match path.path.get_ident() {
+                Some(ident) if primitive(ident) => return self.read(ident, endianness),
+                _ => {}
+            },
+            Type::Array(array) => {
+                let len = &array.len;
+                let elem = &array.elem;
+                if is_u8(elem) {
+                    return quote! {
+                        ::munch::Parser::<#input>::map(::munch::byte::Take(#len), |slice: &[u8]| {
+                            let mut array = [0; #len];
+                            array.copy_from_slice(slice);
+                            array
+                        })
+                    };
+                }
+                let parser = self.parser(elem, endianness);
+                return quote! {
+                    |input: #input, from: usize| -> ::munch::Result<#ty, ::munch::error::Error<'static>> {
+                        let (from, items) = ::munch::Parser::parse(
+                            &mut ::munch::Parser::<#input>::repeat(::munch::P(#parser), #len)
+                                .collect::<::std::vec::Vec<#elem>, #input>(),
+                            input,
+                            from,
+                        )?;
+                        let array = ::std::convert::TryInto::try_into(items);
+                        ::std::result::Result::Ok((from, array.unwrap_or_else(|_| unreachable!())))
+                    }
+                };
+            }
+            _ => {}
+        }
+        quote!(<#ty as ::munch::Parse<#input>>::parse)
+    }
+
+    // A `&[u8]` or `Vec<T>` whose length comes from a prefix or an earlier field.
+    fn sized(&self, ty: &Type, len: &Len, endianness: Endianness) -> Result<TokenStream> {
+        let input = self.input();
+        let len = match len {
+            Len::Prefix(ident) => {
+                let read = self.read(ident, endianness);
+                quote! {
+                    let (from, len) = ::munch::Parser::parse(&mut #read, input, from)?;
+                    let len = len as usize;
+                }
+            }
+            Len::Field(ident) => {
+                let binding = binding(ident);
+                quote!(let len = #binding as usize;)
+            }
+        };
+        let parser = match ty {
+            Type::Reference(reference) if is_u8_slice(&reference.elem) => {
+                quote!(::munch::byte::Take(len))
+            }
+            _ => match vec(ty) {
+                Some(elem) => {
+                    let parser = self.parser(elem, endianness);
+                    quote! {
+                        ::munch::Parser::<#input>::repeat(::munch::P(#parser), len)
+                            .collect::<#ty, #input>()
+                    }
+                }
+                None => {
+                    return Err(Error::new_spanned(
+                        ty,
+                        "`len` requires a `&[u8]` or `Vec<T>` field",
+                    ))
+                }
+            },
+        };
+        Ok(quote! {{
+            #len
+            ::munch::Parser::parse(&mut #parser, input, from)?
+        }})
+    }
+}
+
+fn is_u8(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => path.qself.is_none() && path.path.is_ident("u8"),
+        _ => false,
+    }
+}
+
+fn is_u8_slice(ty: &Type) -> bool {
+    match ty {
+        Type::Slice(slice) => is_u8(&slice.elem),
+        _ => false,
+    }
+}
+
+fn vec(ty: &Type) -> Option<&Type> {
+    let segment = match ty {
+        Type::Path(path) if path.qself.is_none() => path.path.segments.last()?,
+        _ => return None,
+    };
+    if segment.ident != "Vec" {
+        return None;
+    }
+    match &segment.arguments {
+        syn::PathArguments::AngleBracketed(args) => match args.args.first()? {
+            syn::GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+// Fields are bound under private names so that ones called e.g. `from` or `input` can't clash with
+// the generated parser's own locals.
+fn binding(ident: &Ident) -> Ident {
+    format_ident!("__munch_field_{}", ident)
+}
+
+pub fn derive(input: DeriveInput) -> Result<TokenStream> {
+    let ident = &input.ident;
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(Error::new_spanned(
+                ident,
+                "`BinaryParse` can only be derived for structs",
+            ))
+        }
+    };
+    // Network byte order unless specified otherwise.
+    let endianness = Attrs::parse(&input.attrs, false)?
+        .endianness
+        .unwrap_or(Endianness::Big);
+
+    // Borrowed fields like `&'a [u8]` share the lifetime of the input.
+    let mut generics = input.generics.clone();
+    let lifetime = match input.generics.lifetimes().next() {
+        Some(param) => param.lifetime.clone(),
+        None => {
+            let lifetime: Lifetime = parse_quote!('munch);
+            generics
+                .params
+                .push(GenericParam::Lifetime(parse_quote!(#lifetime)));
+            lifetime
+        }
+    };
+    let gen = Gen { lifetime };
+    let input_ty = gen.input();
+
+    let mut body = TokenStream::new();
+    let mut names = Vec::new();
+    let mut bindings = Vec::new();
+    for (index, field) in fields.iter().enumerate() {
+        let attrs = Attrs::parse(&field.attrs, true)?;
+        let endianness = attrs.endianness.unwrap_or(endianness);
+        let binding = match &field.ident {
+            Some(ident) => binding(ident),
+            None => format_ident!("__munch_field_{}", index),
+        };
+        let value: Expr = match &attrs.len {
+            Some(len) => syn::parse2(gen.sized(&field.ty, len, endianness)?)?,
+            None => {
+                let parser = gen.parser(&field.ty, endianness);
+                parse_quote!(::munch::Parser::parse(&mut #parser, input, from)?)
+            }
+        };
+        body.extend(quote!(let (from, #binding) = #value;));
+        names.extend(field.ident.clone());
+        bindings.push(binding);
+    }
+    let value = match fields {
+        Fields::Named(_) => quote!(#ident { #(#names: #bindings),* }),
+        Fields::Unnamed(_) => quote!(#ident(#(#bindings),*)),
+        Fields::Unit => quote!(#ident),
+    };
+
+    let (impl_generics, _, _) = generics.split_for_impl();
+    let (_, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::munch::Parse<#input_ty> for #ident #ty_generics #where_clause {
+            #[inline]
+            fn parse(
+                input: #input_ty,
+                from: usize,
+            ) -> ::munch::Result<Self, ::munch::error::Error<'static>> {
+                #body
+                ::std::result::Result::Ok((from, #value))
+            }
+        }
+    })
+}
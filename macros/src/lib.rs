@@ -0,0 +1,32 @@
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+#[cfg(feature = "binary")]
+mod binary;
+mod parse;
+mod peg;
+
+#[proc_macro_derive(Parse, attributes(munch))]
+pub fn derive_parse(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    parse::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+#[cfg(feature = "binary")]
+#[proc_macro_derive(BinaryParse, attributes(munch))]
+pub fn derive_binary_parse(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    binary::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+#[proc_macro]
+pub fn peg(input: TokenStream) -> TokenStream {
+    let grammar = parse_macro_input!(input as peg::Grammar);
+    peg::expand(grammar).into()
+}
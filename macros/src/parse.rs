@@ -0,0 +1,251 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    parse_quote, Attribute, Data, DeriveInput, Error, Expr, Fields, GenericArgument, LitByteStr,
+    LitStr, PathArguments, Result, Type,
+};
+
+#[derive(Default)]
+struct Attrs {
+    bytes: bool,
+    before: Option<LitStr>,
+    after: Option<LitStr>,
+    sep: Option<LitStr>,
+    with: Option<Expr>,
+    repeat: Option<Expr>,
+}
+
+impl Attrs {
+    fn parse(attrs: &[Attribute], allowed: &[&str]) -> Result<Attrs> {
+        let mut out = Attrs::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("munch")) {
+            attr.parse_nested_meta(|meta| {
+                let name = match meta.path.get_ident() {
+                    Some(ident) if allowed.contains(&ident.to_string().as_str()) => ident,
+                    _ => return Err(meta.error("unsupported munch attribute")),
+                };
+                if name == "bytes" {
+                    out.bytes = true;
+                } else if name == "before" {
+                    out.before = Some(meta.value()?.parse()?);
+                } else if name == "after" {
+                    out.after = Some(meta.value()?.parse()?);
+                } else if name == "sep" {
+                    out.sep = Some(meta.value()?.parse()?);
+                } else if name == "with" {
+                    out.with = Some(meta.value()?.parse()?);
+                } else if meta.input.peek(syn::Token![=]) {
+                    out.repeat = Some(meta.value()?.parse()?);
+                } else {
+                    out.repeat = Some(parse_quote!(..));
+                }
+                Ok(())
+            })?;
+        }
+        Ok(out)
+    }
+}
+
+struct Gen {
+    bytes: bool,
+    input: TokenStream,
+}
+
+impl Gen {
+    fn new(attrs: &Attrs) -> Gen {
+        let input = if attrs.bytes {
+            quote!(&'munch [u8])
+        } else {
+            quote!(&'munch str)
+        };
+        Gen {
+            bytes: attrs.bytes,
+            input,
+        }
+    }
+
+    fn lit(&self, lit: &LitStr) -> TokenStream {
+        if self.bytes {
+            let lit = LitByteStr::new(lit.value().as_bytes(), lit.span());
+            quote!(&#lit[..])
+        } else {
+            quote!(#lit)
+        }
+    }
+
+    fn skip(&self, lit: &Option<LitStr>) -> TokenStream {
+        match lit {
+            Some(lit) => {
+                let lit = self.lit(lit);
+                quote!(let (from, _) = ::munch::Parser::parse(&mut #lit, input, from)?;)
+            }
+            None => quote!(),
+        }
+    }
+
+    fn parser(&self, ty: &Type, attrs: &Attrs) -> Result<TokenStream> {
+        let input = &self.input;
+        let range = match &attrs.repeat {
+            Some(range) => range,
+            None => {
+                if let Some(sep) = &attrs.sep {
+                    return Err(Error::new_spanned(
+                        sep,
+                        "`sep` on a field requires `repeat`",
+                    ));
+                }
+                return Ok(match &attrs.with {
+                    Some(with) => quote!(#with),
+                    None => quote!(<#ty as ::munch::Parse<#input>>::parse),
+                });
+            }
+        };
+        let item = match &attrs.with {
+            Some(with) => quote!(#with),
+            None => {
+                let item = item(ty).ok_or_else(|| {
+                    Error::new_spanned(ty, "`repeat` requires a collection type like `Vec<T>`")
+                })?;
+                quote!(<#item as ::munch::Parse<#input>>::parse)
+            }
+        };
+        let repeat = quote!(::munch::Parser::<#input>::repeat(::munch::P(#item), #range));
+        Ok(match &attrs.sep {
+            Some(sep) => {
+                let sep = self.lit(sep);
+                quote! {
+                    #repeat.join(#sep).fold(
+                        <#ty as ::std::default::Default>::default,
+                        |mut items: #ty, item| {
+                            ::std::iter::Extend::extend(&mut items, ::std::option::Option::Some(item));
+                            items
+                        },
+                        |mut items: #ty, _, item| {
+                            ::std::iter::Extend::extend(&mut items, ::std::option::Option::Some(item));
+                            items
+                        },
+                    )
+                }
+            }
+            None => quote!(#repeat.collect::<#ty, #input>()),
+        })
+    }
+
+    // Statements parsing `fields` in order, ending with `Ok((from, #path { .. }))`.
+    fn fields(&self, attrs: &Attrs, fields: &Fields, path: TokenStream) -> Result<TokenStream> {
+        let mut body = self.skip(&attrs.before);
+        let mut names = Vec::new();
+        for (index, field) in fields.iter().enumerate() {
+            let field_attrs =
+                Attrs::parse(&field.attrs, &["before", "after", "with", "repeat", "sep"])?;
+            if index > 0 {
+                body.extend(self.skip(&attrs.sep));
+            }
+            let name = format_ident!("field{}", index);
+            let parser = self.parser(&field.ty, &field_attrs)?;
+            let before = self.skip(&field_attrs.before);
+            let after = self.skip(&field_attrs.after);
+            body.extend(quote! {
+                #before
+                let (from, #name) = ::munch::Parser::parse(&mut #parser, input, from)?;
+                #after
+            });
+            names.push(name);
+        }
+        body.extend(self.skip(&attrs.after));
+        let value = match fields {
+            Fields::Named(_) => {
+                let idents = fields.iter().map(|field| &field.ident);
+                quote!(#path { #(#idents: #names),* })
+            }
+            Fields::Unnamed(_) => quote!(#path(#(#names),*)),
+            Fields::Unit => path,
+        };
+        body.extend(quote!(::std::result::Result::Ok((from, #value))));
+        Ok(body)
+    }
+}
+
+// The element type of a collection, i.e. the last type argument of `Vec<T>` and the like.
+fn item(ty: &Type) -> Option<&Type> {
+    let path = match ty {
+        Type::Path(path) => &path.path,
+        _ => return None,
+    };
+    match &path.segments.last()?.arguments {
+        PathArguments::AngleBracketed(args) => args.args.iter().rev().find_map(|arg| match arg {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        }),
+        _ => None,
+    }
+}
+
+pub fn derive(input: DeriveInput) -> Result<TokenStream> {
+    let ident = &input.ident;
+    let allowed: &[&str] = match input.data {
+        Data::Enum(_) => &["bytes", "before", "after"],
+        _ => &["bytes", "before", "after", "sep"],
+    };
+    let attrs = Attrs::parse(&input.attrs, allowed)?;
+    let gen = Gen::new(&attrs);
+    let input_ty = &gen.input;
+
+    let body = match &input.data {
+        Data::Struct(data) => gen.fields(&attrs, &data.fields, quote!(#ident))?,
+        Data::Enum(data) => {
+            let mut alternatives = Vec::new();
+            for variant in &data.variants {
+                let variant_attrs = Attrs::parse(&variant.attrs, &["before", "after", "sep"])?;
+                let name = &variant.ident;
+                let body = gen.fields(&variant_attrs, &variant.fields, quote!(#ident::#name))?;
+                alternatives.push(quote! {
+                    |input: #input_ty, from: usize| -> ::munch::Result<Self, ::munch::error::Error<'static>> {
+                        #body
+                    }
+                });
+            }
+            let last = alternatives.pop().ok_or_else(|| {
+                Error::new_spanned(ident, "cannot derive `Parse` for an empty enum")
+            })?;
+            // Variants are tried in order from the same offset, even after one consumed input, so
+            // that variants sharing a prefix (e.g. `Call(Ident, Args)` and `Var(Ident)`) all get a
+            // chance; the error is the one that got the furthest.
+            let parser = alternatives
+                .into_iter()
+                .rev()
+                .fold(last, |b, a| quote!(::munch::Choice(#a, #b)));
+            let before = gen.skip(&attrs.before);
+            let after = gen.skip(&attrs.after);
+            quote! {
+                #before
+                let (from, output) = ::munch::Parser::parse(&mut #parser, input, from)?;
+                #after
+                ::std::result::Result::Ok((from, output))
+            }
+        }
+        Data::Union(data) => {
+            return Err(Error::new_spanned(
+                data.union_token,
+                "cannot derive `Parse` for a union",
+            ))
+        }
+    };
+
+    let mut generics = input.generics.clone();
+    generics.params.push(parse_quote!('munch));
+    let (impl_generics, _, _) = generics.split_for_impl();
+    let (_, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::munch::Parse<#input_ty> for #ident #ty_generics #where_clause {
+            #[inline]
+            fn parse(
+                input: #input_ty,
+                from: usize,
+            ) -> ::munch::Result<Self, ::munch::error::Error<'static>> {
+                #body
+            }
+        }
+    })
+}
@@ -0,0 +1,363 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::{
+    bracketed, parenthesized, token, Error, Ident, LitChar, LitStr, Result, Token, Type, Visibility,
+};
+
+pub struct Grammar(Vec<Rule>);
+
+struct Rule {
+    vis: Visibility,
+    name: Ident,
+    ty: Option<Type>,
+    expr: Expr,
+}
+
+enum Expr {
+    Choice(Vec<Expr>),
+    Seq(Vec<(Option<Ident>, Expr)>, Option<TokenStream>),
+    Str(LitStr),
+    Char(LitChar),
+    Class(bool, Vec<(char, char)>),
+    Any,
+    Rule(Ident),
+    Capture(Box<Expr>),
+    Repeat(Box<Expr>, Repeat),
+    Lookahead(Box<Expr>),
+    Not(Box<Expr>),
+}
+
+#[derive(Copy, Clone)]
+enum Repeat {
+    Many,
+    Many1,
+    Optional,
+}
+
+impl Parse for Grammar {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut rules = Vec::new();
+        while !input.is_empty() {
+            let vis = input.parse()?;
+            let name = input.parse()?;
+            let ty = if input.peek(Token![->]) {
+                input.parse::<Token![->]>()?;
+                Some(input.parse()?)
+            } else {
+                None
+            };
+            input.parse::<Token![=]>()?;
+            let expr = choice(input)?;
+            input.parse::<Token![;]>()?;
+            rules.push(Rule {
+                vis,
+                name,
+                ty,
+                expr,
+            });
+        }
+        Ok(Grammar(rules))
+    }
+}
+
+fn choice(input: ParseStream) -> Result<Expr> {
+    let mut alternatives = vec![seq(input)?];
+    while input.peek(Token![/]) {
+        input.parse::<Token![/]>()?;
+        alternatives.push(seq(input)?);
+    }
+    Ok(if alternatives.len() == 1 {
+        alternatives.pop().unwrap()
+    } else {
+        Expr::Choice(alternatives)
+    })
+}
+
+fn seq(input: ParseStream) -> Result<Expr> {
+    let mut items = Vec::new();
+    loop {
+        if input.is_empty() || input.peek(Token![/]) || input.peek(Token![;]) {
+            break;
+        }
+        if input.peek(token::Brace) {
+            let action = input.step(|cursor| match cursor.token_tree() {
+                Some((tt, rest)) => Ok((TokenStream::from(tt), rest)),
+                None => Err(cursor.error("expected an action block")),
+            })?;
+            return Ok(Expr::Seq(items, Some(action)));
+        }
+        let label = if input.peek(Ident) && input.peek2(Token![:]) && !input.peek2(Token![::]) {
+            let label = input.parse()?;
+            input.parse::<Token![:]>()?;
+            Some(label)
+        } else {
+            None
+        };
+        items.push((label, prefix(input)?));
+    }
+    if items.is_empty() {
+        return Err(input.error("expected an expression"));
+    }
+    if items.len() == 1 && items[0].0.is_none() {
+        return Ok(items.pop().unwrap().1);
+    }
+    Ok(Expr::Seq(items, None))
+}
+
+fn prefix(input: ParseStream) -> Result<Expr> {
+    if input.peek(Token![&]) {
+        input.parse::<Token![&]>()?;
+        Ok(Expr::Lookahead(Box::new(suffix(input)?)))
+    } else if input.peek(Token![!]) {
+        input.parse::<Token![!]>()?;
+        Ok(Expr::Not(Box::new(suffix(input)?)))
+    } else {
+        suffix(input)
+    }
+}
+
+fn suffix(input: ParseStream) -> Result<Expr> {
+    let mut expr = primary(input)?;
+    loop {
+        let repeat = if input.peek(Token![*]) {
+            input.parse::<Token![*]>()?;
+            Repeat::Many
+        } else if input.peek(Token![+]) {
+            input.parse::<Token![+]>()?;
+            Repeat::Many1
+        } else if input.peek(Token![?]) {
+            input.parse::<Token![?]>()?;
+            Repeat::Optional
+        } else {
+            return Ok(expr);
+        };
+        expr = Expr::Repeat(Box::new(expr), repeat);
+    }
+}
+
+fn primary(input: ParseStream) -> Result<Expr> {
+    if input.peek(LitStr) {
+        Ok(Expr::Str(input.parse()?))
+    } else if input.peek(LitChar) {
+        Ok(Expr::Char(input.parse()?))
+    } else if input.peek(Token![.]) {
+        input.parse::<Token![.]>()?;
+        Ok(Expr::Any)
+    } else if input.peek(Token![$]) {
+        input.parse::<Token![$]>()?;
+        let content;
+        parenthesized!(content in input);
+        Ok(Expr::Capture(Box::new(choice(&content)?)))
+    } else if input.peek(token::Paren) {
+        let content;
+        parenthesized!(content in input);
+        choice(&content)
+    } else if input.peek(token::Bracket) {
+        let content;
+        bracketed!(content in input);
+        class(&content)
+    } else if input.peek(Ident) {
+        Ok(Expr::Rule(input.parse()?))
+    } else {
+        Err(input.error("expected an expression"))
+    }
+}
+
+// `['a'..='z' '_']` or `["a-z_"]`, optionally negated with a leading `^`.
+fn class(input: ParseStream) -> Result<Expr> {
+    let negated = if input.peek(Token![^]) {
+        input.parse::<Token![^]>()?;
+        true
+    } else {
+        false
+    };
+    let mut ranges = Vec::new();
+    while !input.is_empty() {
+        if input.peek(LitStr) {
+            let lit: LitStr = input.parse()?;
+            let chars = lit.value().chars().collect::<Vec<_>>();
+            let mut index = 0;
+            while index < chars.len() {
+                if index + 2 < chars.len() && chars[index + 1] == '-' {
+                    if chars[index] > chars[index + 2] {
+                        return Err(Error::new_spanned(lit, "invalid character range"));
+                    }
+                    ranges.push((chars[index], chars[index + 2]));
+                    index += 3;
+                } else {
+                    ranges.push((chars[index], chars[index]));
+                    index += 1;
+                }
+            }
+        } else {
+            let start: LitChar = input.parse()?;
+            if input.peek(Token![..=]) {
+                input.parse::<Token![..=]>()?;
+                let end: LitChar = input.parse()?;
+                if start.value() > end.value() {
+                    return Err(Error::new_spanned(end, "invalid character range"));
+                }
+                ranges.push((start.value(), end.value()));
+            } else {
+                ranges.push((start.value(), start.value()));
+            }
+        }
+    }
+    Ok(Expr::Class(negated, ranges))
+}
+
+impl Expr {
+    fn parser(&self) -> TokenStream {
+        let input = quote!(&'input str);
+        match self {
+            Expr::Choice(alternatives) => {
+                // PEG choice backtracks even if an alternative consumed input, but keeps the error
+                // that got the furthest.
+                let mut alternatives = alternatives.iter().map(Expr::parser).rev();
+                let last = alternatives.next().unwrap();
+                alternatives.fold(last, |b, a| quote!(::munch::Choice(#a, #b)))
+            }
+            Expr::Seq(items, action) => {
+                let parsers = items.iter().map(|(_, expr)| expr.parser());
+                // Everything the expansion binds itself is reserved, so labels can be named freely.
+                let vars = (0..items.len()).map(|index| format_ident!("__munch_p{}", index));
+                let vars2 = vars.clone();
+                let outputs = (0..items.len())
+                    .map(|index| format_ident!("__munch_v{}", index))
+                    .collect::<Vec<_>>();
+                let patterns =
+                    items
+                        .iter()
+                        .zip(&outputs)
+                        .map(|((label, _), output)| match (label, action) {
+                            (Some(label), _) => quote!(#label),
+                            (None, Some(_)) => quote!(_),
+                            (None, None) => quote!(#output),
+                        });
+                let value = match action {
+                    Some(action) => action.clone(),
+                    None => {
+                        let values =
+                            items
+                                .iter()
+                                .zip(&outputs)
+                                .map(|((label, _), output)| match label {
+                                    Some(label) => quote!(#label),
+                                    None => quote!(#output),
+                                });
+                        quote!((#(#values),*))
+                    }
+                };
+                quote! {{
+                    #(let mut #vars = #parsers;)*
+                    move |__munch_input: #input, __munch_from: usize| {
+                        #(let (__munch_from, #patterns) =
+                            match ::munch::Parser::parse(&mut #vars2, __munch_input, __munch_from) {
+                                ::std::result::Result::Ok(ok) => ok,
+                                ::std::result::Result::Err(error) => {
+                                    return ::std::result::Result::Err(error)
+                                }
+                            };)*
+                        ::std::result::Result::Ok((__munch_from, #value))
+                    }
+                }}
+            }
+            Expr::Str(lit) => quote!(#lit),
+            Expr::Char(lit) => quote!(#lit),
+            Expr::Class(negated, ranges) => {
+                let patterns = ranges.iter().map(|&(start, end)| {
+                    if start == end {
+                        quote!(#start)
+                    } else {
+                        quote!(#start..=#end)
+                    }
+                });
+                let class = ranges
+                    .iter()
+                    .map(|&(start, end)| quote!(.range(#start..=#end)));
+                let (not, error) = if *negated {
+                    (quote!(!), quote!(NoneOf))
+                } else {
+                    (quote!(), quote!(OneOf))
+                };
+                // The class only describes the failure, so it's built once per thread and shared
+                // by every error after that.
+                quote! {{
+                    ::std::thread_local! {
+                        static __MUNCH_CLASS: ::munch::class::Class =
+                            ::munch::class::Class::new()#(#class)*;
+                    }
+                    ::munch::Parser::<#input>::map_err(
+                        ::munch::str::Satisfy(|char: char| #not match char {
+                            #(#patterns)|* => true,
+                            _ => false,
+                        }),
+                        |_| ::munch::error::Error::#error(
+                            __MUNCH_CLASS.with(::std::clone::Clone::clone),
+                        ),
+                    )
+                }}
+            }
+            Expr::Any => quote!(::munch::str::Any),
+            Expr::Rule(name) => quote!(#name),
+            Expr::Capture(expr) => {
+                let parser = expr.parser();
+                quote!(::munch::str::Capture(#parser))
+            }
+            Expr::Repeat(expr, repeat) => {
+                let parser = expr.parser();
+                match repeat {
+                    Repeat::Many => {
+                        quote!(::munch::Parser::<#input>::repeat(::munch::Try(#parser), ..))
+                    }
+                    Repeat::Many1 => {
+                        quote!(::munch::Parser::<#input>::repeat(::munch::Try(#parser), 1..))
+                    }
+                    Repeat::Optional => {
+                        quote!(::munch::Parser::<#input>::optional(::munch::Try(#parser)))
+                    }
+                }
+            }
+            Expr::Lookahead(expr) => {
+                let parser = expr.parser();
+                quote!(::munch::Parser::<#input>::map(::munch::Lookahead(#parser), |_| ()))
+            }
+            Expr::Not(expr) => {
+                let parser = expr.parser();
+                quote!(::munch::Not(#parser))
+            }
+        }
+    }
+}
+
+pub fn expand(grammar: Grammar) -> TokenStream {
+    grammar
+        .0
+        .iter()
+        .map(|rule| {
+            let Rule {
+                vis,
+                name,
+                ty,
+                expr,
+            } = rule;
+            let parser = expr.parser();
+            let (ty, parser) = match ty {
+                Some(ty) => (quote!(#ty), parser),
+                None => (
+                    quote!(()),
+                    quote!(::munch::Parser::<&'input str>::map(#parser, |_| ())),
+                ),
+            };
+            quote! {
+                #vis fn #name<'input>(
+                    __munch_input: &'input str,
+                    __munch_from: usize,
+                ) -> ::munch::Result<#ty, ::munch::error::Error<'static>> {
+                    ::munch::Parser::parse(&mut #parser, __munch_input, __munch_from)
+                }
+            }
+        })
+        .collect()
+}
@@ -1,5 +1,6 @@
+use crate::class::Class;
 use crate::error::{Ascii, Error};
-use crate::{str, Parser, Result};
+use crate::{input, Parser, Result};
 
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct Satisfy<F>(pub F)
@@ -16,13 +17,54 @@ where
 
     #[inline(always)]
     fn parse(&mut self, input: &'a Input, from: usize) -> Result<Self::Output, Self::Error> {
-        let input = input.as_ref();
-        if let Some(&u8) = input.get(from) {
-            if u8 <= 0x7F && self.0(u8) {
-                return Ok((from + 1, u8));
-            }
+        let f = &mut self.0;
+        match input::Satisfy(|u8| u8 <= 0x7F && f(u8)).parse(input.as_ref(), from) {
+            Err((from, _)) => Err((from, Error::Ascii(Ascii::Satisfy))),
+            otherwise => otherwise,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct OneOf<'tmp>(pub &'tmp Class);
+
+impl<'a, 'tmp, Input> Parser<&'a Input> for OneOf<'tmp>
+where
+    Input: AsRef<[u8]> + ?Sized,
+{
+    type Output = u8;
+    type Error = Error<'static>;
+
+    #[inline(always)]
+    fn parse(&mut self, input: &'a Input, from: usize) -> Result<Self::Output, Self::Error> {
+        let class = self.0;
+        match input::Satisfy(|u8| u8 <= 0x7F && class.contains_byte(u8)).parse(input.as_ref(), from)
+        {
+            Err((from, _)) => Err((from, Error::OneOf(class.clone()))),
+            Ok(ok) => Ok(ok),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct NoneOf<'tmp>(pub &'tmp Class);
+
+impl<'a, 'tmp, Input> Parser<&'a Input> for NoneOf<'tmp>
+where
+    Input: AsRef<[u8]> + ?Sized,
+{
+    type Output = u8;
+    type Error = Error<'static>;
+
+    #[inline(always)]
+    fn parse(&mut self, input: &'a Input, from: usize) -> Result<Self::Output, Self::Error> {
+        let class = self.0;
+        match input::Satisfy(|u8| u8 <= 0x7F && !class.contains_byte(u8))
+            .parse(input.as_ref(), from)
+        {
+            Err((from, _)) => Err((from, Error::NoneOf(class.clone()))),
+            Ok(ok) => Ok(ok),
         }
-        Err((from, Error::Ascii(Ascii::Satisfy)))
     }
 }
 
@@ -41,16 +83,9 @@ where
 
     #[inline(always)]
     fn parse(&mut self, input: &'a Input, from: usize) -> Result<Self::Output, Self::Error> {
-        let input = input.as_ref();
-        let to = match input[from..]
-            .iter()
-            .position(|&u8| u8 > 0x7F || !self.0(u8))
-        {
-            Some(position) => from + position,
-            None => input.len(),
-        };
-
-        Ok((to, std::str::from_utf8(&input[from..to]).unwrap()))
+        let f = &mut self.0;
+        let (to, slice) = input::TakeWhile(|u8| u8 <= 0x7F && f(u8)).parse(input.as_ref(), from)?;
+        Ok((to, std::str::from_utf8(slice).unwrap()))
     }
 }
 
@@ -75,3 +110,62 @@ where
         }
     }
 }
+
+// `TakeWhile` and `TakeWhile1` over the ASCII members of a class, failing with `Error::OneOf`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TakeWhileOneOf<'tmp>(pub &'tmp Class);
+
+impl<'a, 'tmp, Input> Parser<&'a Input> for TakeWhileOneOf<'tmp>
+where
+    Input: AsRef<[u8]> + ?Sized,
+{
+    type Output = &'a str;
+    type Error = Error<'static>;
+
+    #[inline(always)]
+    fn parse(&mut self, input: &'a Input, from: usize) -> Result<Self::Output, Self::Error> {
+        let class = self.0;
+        TakeWhile(|u8| class.contains_byte(u8)).parse(input, from)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TakeWhile1OneOf<'tmp>(pub &'tmp Class);
+
+impl<'a, 'tmp, Input> Parser<&'a Input> for TakeWhile1OneOf<'tmp>
+where
+    Input: AsRef<[u8]> + ?Sized,
+{
+    type Output = &'a str;
+    type Error = Error<'static>;
+
+    #[inline(always)]
+    fn parse(&mut self, input: &'a Input, from: usize) -> Result<Self::Output, Self::Error> {
+        match TakeWhileOneOf(self.0).parse(input, from) {
+            Ok((_, "")) => Err((from, Error::OneOf(self.0.clone()))),
+            otherwise => otherwise,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct NoCase<'tmp>(pub &'tmp [u8]);
+
+impl<'a, 'tmp, Input> Parser<&'a Input> for NoCase<'tmp>
+where
+    Input: AsRef<[u8]> + ?Sized,
+{
+    type Output = &'a str;
+    type Error = Error<'tmp>;
+
+    #[inline(always)]
+    fn parse(&mut self, input: &'a Input, from: usize) -> Result<Self::Output, Self::Error> {
+        let to = from + self.0.len();
+        match input.as_ref().get(from..to) {
+            Some(bytes) if bytes.is_ascii() && bytes.eq_ignore_ascii_case(self.0) => {
+                Ok((to, std::str::from_utf8(bytes).unwrap()))
+            }
+            _ => Err((from, Error::NoCaseBytes(self.0))),
+        }
+    }
+}
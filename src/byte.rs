@@ -1,5 +1,6 @@
+use crate::class::Class;
 use crate::error::{BinaryType, Endianness, Error};
-use crate::{Parser, Result};
+use crate::{input, scan, Parser, Result};
 
 impl<'a> Parser<&'a [u8]> for u8 {
     type Output = u8;
@@ -21,11 +22,11 @@ impl<'a, 'tmp> Parser<&'a [u8]> for &'tmp [u8] {
 
     #[inline(always)]
     fn parse(&mut self, input: &'a [u8], from: usize) -> Result<Self::Output, Self::Error> {
-        if input[from..].starts_with(*self) {
+        if input[from..].starts_with(self) {
             let to = from + self.len();
             Ok((to, &input[from..to]))
         } else {
-            Err((from, Error::Bytes(*self)))
+            Err((from, Error::Bytes(self)))
         }
     }
 }
@@ -44,12 +45,41 @@ where
 
     #[inline(always)]
     fn parse(&mut self, input: &'a [u8], from: usize) -> Result<Self::Output, Self::Error> {
-        if let Some(&byte) = input.get(from) {
-            if self.0(byte) {
-                return Ok((from + 1, byte));
-            }
+        input::Satisfy(&mut self.0).parse(input, from)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct OneOf<'tmp>(pub &'tmp Class);
+
+impl<'a, 'tmp> Parser<&'a [u8]> for OneOf<'tmp> {
+    type Output = u8;
+    type Error = Error<'static>;
+
+    #[inline(always)]
+    fn parse(&mut self, input: &'a [u8], from: usize) -> Result<Self::Output, Self::Error> {
+        let class = self.0;
+        match input::Satisfy(|u8| class.contains_byte(u8)).parse(input, from) {
+            Err((from, _)) => Err((from, Error::OneOf(class.clone()))),
+            Ok(ok) => Ok(ok),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct NoneOf<'tmp>(pub &'tmp Class);
+
+impl<'a, 'tmp> Parser<&'a [u8]> for NoneOf<'tmp> {
+    type Output = u8;
+    type Error = Error<'static>;
+
+    #[inline(always)]
+    fn parse(&mut self, input: &'a [u8], from: usize) -> Result<Self::Output, Self::Error> {
+        let class = self.0;
+        match input::Satisfy(|u8| !class.contains_byte(u8)).parse(input, from) {
+            Err((from, _)) => Err((from, Error::NoneOf(class.clone()))),
+            Ok(ok) => Ok(ok),
         }
-        Err((from, Error::Satisfy))
     }
 }
 
@@ -67,11 +97,7 @@ where
 
     #[inline(always)]
     fn parse(&mut self, input: &'a [u8], from: usize) -> Result<Self::Output, Self::Error> {
-        let to = match input[from..].iter().position(|&byte| !self.0(byte)) {
-            Some(position) => from + position,
-            None => input.len(),
-        };
-        Ok((to, &input[from..to]))
+        input::TakeWhile(&mut self.0).parse(input, from)
     }
 }
 
@@ -89,13 +115,95 @@ where
 
     #[inline(always)]
     fn parse(&mut self, input: &'a [u8], from: usize) -> Result<Self::Output, Self::Error> {
-        match TakeWhile(&mut self.0).parse(input, from) {
-            Ok((_, b"")) => Err((from, Error::TakeWhile1)),
+        input::TakeWhile1(&mut self.0).parse(input, from)
+    }
+}
+
+// `TakeWhile` and `TakeWhile1` over the members of a class, failing with `Error::OneOf`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TakeWhileOneOf<'tmp>(pub &'tmp Class);
+
+impl<'a, 'tmp> Parser<&'a [u8]> for TakeWhileOneOf<'tmp> {
+    type Output = &'a [u8];
+    type Error = Error<'static>;
+
+    #[inline(always)]
+    fn parse(&mut self, input: &'a [u8], from: usize) -> Result<Self::Output, Self::Error> {
+        let class = self.0;
+        input::TakeWhile(|u8| class.contains_byte(u8)).parse(input, from)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TakeWhile1OneOf<'tmp>(pub &'tmp Class);
+
+impl<'a, 'tmp> Parser<&'a [u8]> for TakeWhile1OneOf<'tmp> {
+    type Output = &'a [u8];
+    type Error = Error<'static>;
+
+    #[inline(always)]
+    fn parse(&mut self, input: &'a [u8], from: usize) -> Result<Self::Output, Self::Error> {
+        match TakeWhileOneOf(self.0).parse(input, from) {
+            Ok((_, [])) => Err((from, Error::OneOf(self.0.clone()))),
             otherwise => otherwise,
         }
     }
 }
 
+// Takes everything up to the first occurrence of a byte or byte string, or up to the end of input
+// if there is none.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TakeUntil<T>(pub T);
+
+impl<'a> Parser<&'a [u8]> for TakeUntil<u8> {
+    type Output = &'a [u8];
+    type Error = Error<'static>;
+
+    #[inline(always)]
+    fn parse(&mut self, input: &'a [u8], from: usize) -> Result<Self::Output, Self::Error> {
+        let rest = &input[from..];
+        let len = scan::find_byte(rest, self.0).unwrap_or(rest.len());
+        Ok((from + len, &rest[..len]))
+    }
+}
+
+impl<'a> Parser<&'a [u8]> for TakeUntil<&[u8]> {
+    type Output = &'a [u8];
+    type Error = Error<'static>;
+
+    #[inline(always)]
+    fn parse(&mut self, input: &'a [u8], from: usize) -> Result<Self::Output, Self::Error> {
+        let rest = &input[from..];
+        let len = scan::find(rest, self.0).unwrap_or(rest.len());
+        Ok((from + len, &rest[..len]))
+    }
+}
+
+impl<'a, const N: usize> Parser<&'a [u8]> for TakeUntil<&[u8; N]> {
+    type Output = &'a [u8];
+    type Error = Error<'static>;
+
+    #[inline(always)]
+    fn parse(&mut self, input: &'a [u8], from: usize) -> Result<Self::Output, Self::Error> {
+        TakeUntil(&self.0[..]).parse(input, from)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TakeUntilAny<const N: usize>(pub [u8; N]);
+
+impl<'a, const N: usize> Parser<&'a [u8]> for TakeUntilAny<N> {
+    type Output = &'a [u8];
+    type Error = Error<'static>;
+
+    #[inline(always)]
+    fn parse(&mut self, input: &'a [u8], from: usize) -> Result<Self::Output, Self::Error> {
+        let rest = &input[from..];
+        let len = scan::find_any(rest, &self.0).unwrap_or(rest.len());
+        Ok((from + len, &rest[..len]))
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct Capture<P>(pub P);
 
@@ -108,8 +216,23 @@ where
 
     #[inline(always)]
     fn parse(&mut self, input: &'a [u8], from: usize) -> Result<Self::Output, Self::Error> {
-        let (to, _) = self.0.parse(input, from)?;
-        Ok((to, &input[from..to]))
+        input::Capture(self.0.by_ref()).parse(input, from)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SkipUntil<P>(pub P);
+
+impl<'a, P> Parser<&'a [u8]> for SkipUntil<P>
+where
+    P: Parser<&'a [u8]>,
+{
+    type Output = &'a [u8];
+    type Error = P::Error;
+
+    #[inline(always)]
+    fn parse(&mut self, input: &'a [u8], from: usize) -> Result<Self::Output, Self::Error> {
+        input::SkipUntil(self.0.by_ref()).parse(input, from)
     }
 }
 
@@ -122,11 +245,7 @@ impl<'a> Parser<&'a [u8]> for Any {
 
     #[inline(always)]
     fn parse(&mut self, input: &'a [u8], from: usize) -> Result<Self::Output, Self::Error> {
-        if let Some(&byte) = input.get(from) {
-            Ok((from + 1, byte))
-        } else {
-            Err((from, Error::Any))
-        }
+        input::Any.parse(input, from)
     }
 }
 
@@ -139,11 +258,7 @@ impl<'a> Parser<&'a [u8]> for Peek {
 
     #[inline(always)]
     fn parse(&mut self, input: &'a [u8], from: usize) -> Result<Self::Output, Self::Error> {
-        if let Some(&byte) = input.get(from) {
-            Ok((from, byte))
-        } else {
-            Err((from, Error::Peek))
-        }
+        input::Peek.parse(input, from)
     }
 }
 
@@ -156,12 +271,7 @@ impl<'a> Parser<&'a [u8]> for Take {
 
     #[inline(always)]
     fn parse(&mut self, input: &'a [u8], from: usize) -> Result<Self::Output, Self::Error> {
-        let to = from + self.0;
-        if to <= input.len() {
-            Ok((to, &input[from..to]))
-        } else {
-            Err((from, Error::Take(self.0)))
-        }
+        input::Take(self.0).parse(input, from)
     }
 }
 
@@ -174,11 +284,7 @@ impl<'a> Parser<&'a [u8]> for End {
 
     #[inline(always)]
     fn parse(&mut self, input: &'a [u8], from: usize) -> Result<Self::Output, Self::Error> {
-        if input.len() == from {
-            Ok((from, ()))
-        } else {
-            Err((from, Error::End))
-        }
+        input::End.parse(input, from)
     }
 }
 
@@ -0,0 +1,201 @@
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::sync::Arc;
+
+// A set of characters (or, for `byte` and `ascii` parsers, of bytes `0..=255`) built from single
+// characters, ranges, unions and negation. Members up to `0xFF` are kept in a bitset so that the
+// common case is a single lookup. The ranges are shared, so a class is cheap to clone into errors.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Class {
+    table: [u64; 4],
+    // Sorted, non-overlapping and non-adjacent.
+    ranges: Arc<[(char, char)]>,
+}
+
+impl Default for Class {
+    #[inline]
+    fn default() -> Self {
+        Class {
+            table: [0; 4],
+            ranges: Arc::new([]),
+        }
+    }
+}
+
+impl Class {
+    #[inline]
+    pub fn new() -> Self {
+        Class::default()
+    }
+
+    #[inline]
+    pub fn char(self, char: char) -> Self {
+        self.range(char..=char)
+    }
+
+    pub fn chars(mut self, chars: &str) -> Self {
+        for char in chars.chars() {
+            self = self.char(char);
+        }
+        self
+    }
+
+    pub fn range(self, range: RangeInclusive<char>) -> Self {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return self;
+        }
+        let mut ranges = self.ranges.to_vec();
+        ranges.push((start, end));
+        Class::from_ranges(ranges)
+    }
+
+    pub fn union(self, other: &Class) -> Self {
+        let mut ranges = self.ranges.to_vec();
+        ranges.extend_from_slice(&other.ranges);
+        Class::from_ranges(ranges)
+    }
+
+    pub fn negate(self) -> Self {
+        let mut ranges = Vec::new();
+        let mut start = Some('\0');
+        for &(from, to) in self.ranges.iter() {
+            if let Some(start) = start {
+                if start < from {
+                    ranges.push((start, prev(from).unwrap()));
+                }
+            }
+            start = next(to);
+        }
+        if let Some(start) = start {
+            ranges.push((start, char::MAX));
+        }
+        Class::from_ranges(ranges)
+    }
+
+    #[inline(always)]
+    pub fn contains(&self, char: char) -> bool {
+        let u32 = char as u32;
+        if u32 <= 0xFF {
+            self.table[(u32 >> 6) as usize] & (1 << (u32 & 63)) != 0
+        } else {
+            self.ranges
+                .binary_search_by(|&(from, to)| {
+                    if to < char {
+                        std::cmp::Ordering::Less
+                    } else if from > char {
+                        std::cmp::Ordering::Greater
+                    } else {
+                        std::cmp::Ordering::Equal
+                    }
+                })
+                .is_ok()
+        }
+    }
+
+    #[inline(always)]
+    pub fn contains_byte(&self, u8: u8) -> bool {
+        self.table[(u8 >> 6) as usize] & (1 << (u8 & 63)) != 0
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    fn from_ranges(mut ranges: Vec<(char, char)>) -> Self {
+        ranges.sort_unstable();
+        let mut merged: Vec<(char, char)> = Vec::with_capacity(ranges.len());
+        for (from, to) in ranges {
+            match merged.last_mut() {
+                Some(last) if !matches!(next(last.1), Some(next) if from > next) => {
+                    last.1 = last.1.max(to);
+                }
+                _ => merged.push((from, to)),
+            }
+        }
+
+        let mut table = [0; 4];
+        for &(from, to) in &merged {
+            for u32 in from as u32..=(to as u32).min(0xFF) {
+                table[(u32 >> 6) as usize] |= 1 << (u32 & 63);
+            }
+        }
+        Class {
+            table,
+            ranges: merged.into(),
+        }
+    }
+}
+
+#[inline]
+fn next(char: char) -> Option<char> {
+    match char {
+        '\u{D7FF}' => Some('\u{E000}'),
+        char => std::char::from_u32(char as u32 + 1),
+    }
+}
+
+#[inline]
+fn prev(char: char) -> Option<char> {
+    match char {
+        '\u{E000}' => Some('\u{D7FF}'),
+        char => (char as u32).checked_sub(1).and_then(std::char::from_u32),
+    }
+}
+
+impl std::ops::BitOr for Class {
+    type Output = Class;
+
+    #[inline]
+    fn bitor(self, other: Class) -> Class {
+        self.union(&other)
+    }
+}
+
+impl std::ops::Not for Class {
+    type Output = Class;
+
+    #[inline]
+    fn not(self) -> Class {
+        self.negate()
+    }
+}
+
+fn write_char(f: &mut fmt::Formatter, char: char) -> fmt::Result {
+    match char {
+        '\\' | ']' | '[' | '^' | '-' => write!(f, "\\{}", char),
+        char if char.is_control() || char.is_whitespace() && char != ' ' => {
+            write!(f, "{}", char.escape_debug())
+        }
+        char => write!(f, "{}", char),
+    }
+}
+
+fn write_ranges(f: &mut fmt::Formatter, ranges: &[(char, char)]) -> fmt::Result {
+    for &(from, to) in ranges {
+        write_char(f, from)?;
+        if from != to {
+            if next(from) != Some(to) {
+                f.write_str("-")?;
+            }
+            write_char(f, to)?;
+        }
+    }
+    Ok(())
+}
+
+// Printed like a regex class, e.g. `[a-zA-Z_]`, or `[^0-9]` if that is shorter.
+impl fmt::Display for Class {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("[")?;
+        let negated = self.clone().negate();
+        if negated.ranges.len() < self.ranges.len() {
+            f.write_str("^")?;
+            write_ranges(f, &negated.ranges)?;
+        } else {
+            write_ranges(f, &self.ranges)?;
+        }
+        f.write_str("]")
+    }
+}
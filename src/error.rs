@@ -1,3 +1,8 @@
+use std::borrow::Cow;
+use std::fmt;
+
+use crate::class::Class;
+
 #[derive(Clone, Debug, PartialEq)]
 pub enum Error<'a> {
     Any,
@@ -9,12 +14,59 @@ pub enum Error<'a> {
     End,
     Peek,
     Satisfy,
+    NoCase(&'a str),
+    NoCaseBytes(&'a [u8]),
+    OneOf(Class),
+    NoneOf(Class),
+    Number(Number),
     Str(&'a str),
     Take(usize),
     TakeWhile1,
+    Token(Option<Shown<'a>>, Option<Shown<'a>>),
+    Not,
+    Incomplete(Needed),
+    Expected(Vec<Error<'a>>),
+    Label(&'a str),
+    Context(Cow<'a, str>, usize, Box<Error<'a>>),
     Message(Box<str>),
 }
 
+// A token (or, from a lexer, a character) kept as is until the error is displayed, so that failing
+// token parsers don't format anything. Tokens are borrowed from the parser or the input and compare
+// by identity, which keeps merging their errors cheap too.
+#[derive(Copy, Clone)]
+pub enum Shown<'a> {
+    Debug(&'a (dyn fmt::Debug + Sync)),
+    Char(char),
+}
+
+impl<'a> fmt::Debug for Shown<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Shown::Debug(token) => token.fmt(f),
+            Shown::Char(char) => char.fmt(f),
+        }
+    }
+}
+
+impl<'a> PartialEq for Shown<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Shown::Debug(a), Shown::Debug(b)) => {
+                std::ptr::eq(*a as *const _ as *const u8, *b as *const _ as *const u8)
+            }
+            (Shown::Char(a), Shown::Char(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Needed {
+    Unknown,
+    Size(usize),
+}
+
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum Endianness {
     Little,
@@ -36,6 +88,22 @@ pub enum BinaryType {
     f64,
 }
 
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Radix {
+    Binary,
+    Octal,
+    Decimal,
+    Hexadecimal,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Number {
+    Integer,
+    Float,
+    Digit(Radix),
+    Overflow(&'static str),
+}
+
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum Ascii {
     Satisfy,
@@ -45,3 +113,156 @@ pub enum Ascii {
 pub fn message<'a>(message: impl Into<String>) -> Error<'a> {
     Error::Message(message.into().into_boxed_str())
 }
+
+impl<'a> Error<'a> {
+    // The token a token-level failure ran into, `Some(None)` at the end of the tokens.
+    fn found(&self) -> Option<Option<Shown<'a>>> {
+        match self {
+            Error::Token(_, found) => Some(*found),
+            Error::Expected(errors) => errors.iter().find_map(Error::found),
+            _ => None,
+        }
+    }
+
+    fn describe(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Any => write!(f, "any character"),
+            Error::Ascii(Ascii::Satisfy) => write!(f, "a matching ASCII character"),
+            Error::Ascii(Ascii::TakeWhile1) => write!(f, "one or more matching ASCII characters"),
+            Error::Binary(endianness, ty) => {
+                let endianness = match endianness {
+                    Endianness::Little => "little",
+                    Endianness::Big => "big",
+                };
+                write!(f, "a {}-endian {:?}", endianness, ty)
+            }
+            Error::Byte(byte) => write!(f, "b'{}'", escape(&[*byte])),
+            Error::Bytes(bytes) => write!(f, "b\"{}\"", escape(bytes)),
+            Error::Char(char) => write!(f, "{:?}", char),
+            Error::End => write!(f, "end of input"),
+            Error::NoCase(str) => write!(f, "{:?} (case-insensitive)", str),
+            Error::NoCaseBytes(bytes) => write!(f, "b\"{}\" (case-insensitive)", escape(bytes)),
+            Error::OneOf(class) => write!(f, "one of {}", class),
+            Error::NoneOf(class) => write!(f, "none of {}", class),
+            Error::Number(Number::Integer) => write!(f, "an integer"),
+            Error::Number(Number::Float) => write!(f, "a number"),
+            Error::Number(Number::Digit(radix)) => match radix {
+                Radix::Binary => write!(f, "a binary digit"),
+                Radix::Octal => write!(f, "an octal digit"),
+                Radix::Decimal => write!(f, "a decimal digit"),
+                Radix::Hexadecimal => write!(f, "a hexadecimal digit"),
+            },
+            Error::Number(Number::Overflow(ty)) => write!(f, "a number that fits in {}", ty),
+            Error::Peek => write!(f, "any character"),
+            Error::Satisfy => write!(f, "a matching character"),
+            Error::Str(str) => write!(f, "{:?}", str),
+            Error::Take(1) => write!(f, "1 byte"),
+            Error::Take(n) => write!(f, "{} bytes", n),
+            Error::TakeWhile1 => write!(f, "one or more matching characters"),
+            Error::Token(Some(token), _) => write!(f, "{:?}", token),
+            Error::Token(None, _) => write!(f, "a matching token"),
+            Error::Not => write!(f, "something else"),
+            Error::Incomplete(Needed::Unknown) => write!(f, "more input"),
+            Error::Incomplete(Needed::Size(1)) => write!(f, "1 more byte"),
+            Error::Incomplete(Needed::Size(n)) => write!(f, "{} more bytes", n),
+            Error::Expected(errors) => {
+                for (i, error) in errors.iter().enumerate() {
+                    if i > 0 {
+                        let separator = if i + 1 == errors.len() { " or " } else { ", " };
+                        f.write_str(separator)?;
+                    }
+                    error.describe(f)?;
+                }
+                Ok(())
+            }
+            Error::Label(label) => write!(f, "{}", label),
+            Error::Context(_, _, error) => error.describe(f),
+            Error::Message(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl<'a> Default for Error<'a> {
+    #[inline]
+    fn default() -> Self {
+        Error::Expected(Vec::new())
+    }
+}
+
+impl<'a> fmt::Display for Error<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Message(message) => write!(f, "{}", message),
+            Error::Context(context, _, error) => write!(f, "in {} → {}", context, error),
+            Error::Expected(errors) if errors.is_empty() => write!(f, "unexpected input"),
+            Error::Not => write!(f, "unexpected input"),
+            error => {
+                f.write_str("expected ")?;
+                error.describe(f)?;
+                match error.found() {
+                    Some(Some(found)) => write!(f, ", found {:?}", found),
+                    Some(None) => write!(f, ", found end of input"),
+                    None => Ok(()),
+                }
+            }
+        }
+    }
+}
+
+pub trait Merge {
+    #[inline(always)]
+    fn merge(self, other: Self) -> Self
+    where
+        Self: Sized,
+    {
+        other
+    }
+}
+
+impl<'a> Merge for Error<'a> {
+    fn merge(self, other: Self) -> Self {
+        let mut errors = match self {
+            Error::Expected(errors) => errors,
+            error => vec![error],
+        };
+        let other = match other {
+            Error::Expected(errors) => errors,
+            error => vec![error],
+        };
+        for error in other {
+            if !errors.contains(&error) {
+                errors.push(error);
+            }
+        }
+        if errors.len() == 1 {
+            errors.pop().unwrap()
+        } else {
+            Error::Expected(errors)
+        }
+    }
+}
+
+impl Merge for () {}
+
+pub trait Incomplete {
+    fn needed(&self) -> Option<Needed>;
+}
+
+impl<'a> Incomplete for Error<'a> {
+    fn needed(&self) -> Option<Needed> {
+        match self {
+            Error::Incomplete(needed) => Some(*needed),
+            Error::Expected(errors) => errors.iter().find_map(Incomplete::needed),
+            Error::Context(_, _, error) => error.needed(),
+            _ => None,
+        }
+    }
+}
+
+fn escape(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .flat_map(|&byte| std::ascii::escape_default(byte))
+        .map(char::from)
+        .collect()
+}
@@ -0,0 +1,324 @@
+use crate::error::{Error, Needed};
+use crate::{Parser, Result};
+
+pub trait Input: Copy {
+    type Token: Copy;
+    type Slice;
+
+    fn len(&self) -> usize;
+
+    // The token starting at `at` and the offset right after it.
+    fn token(&self, at: usize) -> Option<(Self::Token, usize)>;
+
+    fn slice(&self, from: usize, to: usize) -> Self::Slice;
+
+    // The offset `count` tokens after `from`, if there are that many.
+    #[inline(always)]
+    fn advance(&self, mut from: usize, count: usize) -> Option<usize> {
+        for _ in 0..count {
+            from = self.token(from)?.1;
+        }
+        Some(from)
+    }
+
+    #[inline(always)]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[inline(always)]
+    fn is_complete(&self) -> bool {
+        true
+    }
+
+    // The offset of the first token from `from` on that doesn't satisfy `f`.
+    #[inline(always)]
+    fn take_while<F>(&self, mut from: usize, mut f: F) -> usize
+    where
+        F: FnMut(Self::Token) -> bool,
+    {
+        while let Some((token, to)) = self.token(from) {
+            if !f(token) {
+                break;
+            }
+            from = to;
+        }
+        from
+    }
+}
+
+impl<'a> Input for &'a str {
+    type Token = char;
+    type Slice = &'a str;
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        str::len(self)
+    }
+
+    #[inline(always)]
+    fn token(&self, at: usize) -> Option<(Self::Token, usize)> {
+        let char = self.get(at..)?.chars().next()?;
+        Some((char, at + char.len_utf8()))
+    }
+
+    #[inline(always)]
+    fn slice(&self, from: usize, to: usize) -> Self::Slice {
+        let str: &'a str = self;
+        &str[from..to]
+    }
+
+    #[inline(always)]
+    fn take_while<F>(&self, from: usize, mut f: F) -> usize
+    where
+        F: FnMut(Self::Token) -> bool,
+    {
+        let mut chars = self[from..].chars();
+        match chars.by_ref().find(|&char| !f(char)) {
+            Some(char) => self.len() - chars.as_str().len() - char.len_utf8(),
+            None => self.len(),
+        }
+    }
+}
+
+impl<'a, T> Input for &'a [T]
+where
+    T: Copy,
+{
+    type Token = T;
+    type Slice = &'a [T];
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        <[T]>::len(self)
+    }
+
+    #[inline(always)]
+    fn token(&self, at: usize) -> Option<(Self::Token, usize)> {
+        self.get(at).map(|&token| (token, at + 1))
+    }
+
+    #[inline(always)]
+    fn slice(&self, from: usize, to: usize) -> Self::Slice {
+        let slice: &'a [T] = self;
+        &slice[from..to]
+    }
+
+    #[inline(always)]
+    fn take_while<F>(&self, from: usize, mut f: F) -> usize
+    where
+        F: FnMut(Self::Token) -> bool,
+    {
+        match self[from..].iter().position(|&token| !f(token)) {
+            Some(position) => from + position,
+            None => self.len(),
+        }
+    }
+
+    #[inline(always)]
+    fn advance(&self, from: usize, count: usize) -> Option<usize> {
+        Some(from + count).filter(|&to| to <= self.len())
+    }
+}
+
+// `Incomplete` is reported at the offset the input has to extend to (or just past its end when
+// that isn't known) instead of where the parser started. Being past `from`, it looks like a
+// committed failure to `Or`, `Optional` and `Repeat`, so none of them settle for an alternative
+// that more input could still rule out.
+#[inline(always)]
+pub(crate) fn incomplete<O>(len: usize, to: Option<usize>) -> Result<O, Error<'static>> {
+    match to {
+        Some(to) => Err((to, Error::Incomplete(Needed::Size(to - len)))),
+        None => Err((len + 1, Error::Incomplete(Needed::Unknown))),
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Satisfy<F>(pub F);
+
+impl<I, F> Parser<I> for Satisfy<F>
+where
+    I: Input,
+    F: FnMut(I::Token) -> bool,
+{
+    type Output = I::Token;
+    type Error = Error<'static>;
+
+    #[inline(always)]
+    fn parse(&mut self, input: I, from: usize) -> Result<Self::Output, Self::Error> {
+        match input.token(from) {
+            Some((token, to)) if self.0(token) => Ok((to, token)),
+            None if !input.is_complete() => incomplete(input.len(), Some(from + 1)),
+            _ => Err((from, Error::Satisfy)),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TakeWhile<F>(pub F);
+
+impl<I, F> Parser<I> for TakeWhile<F>
+where
+    I: Input,
+    F: FnMut(I::Token) -> bool,
+{
+    type Output = I::Slice;
+    type Error = Error<'static>;
+
+    #[inline(always)]
+    fn parse(&mut self, input: I, from: usize) -> Result<Self::Output, Self::Error> {
+        if from >= input.len() && !input.is_complete() {
+            return incomplete(input.len(), None);
+        }
+        match input.take_while(from, &mut self.0) {
+            to if to == input.len() && !input.is_complete() => incomplete(input.len(), None),
+            to => Ok((to, input.slice(from, to))),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TakeWhile1<F>(pub F);
+
+impl<I, F> Parser<I> for TakeWhile1<F>
+where
+    I: Input,
+    F: FnMut(I::Token) -> bool,
+{
+    type Output = I::Slice;
+    type Error = Error<'static>;
+
+    #[inline(always)]
+    fn parse(&mut self, input: I, from: usize) -> Result<Self::Output, Self::Error> {
+        match TakeWhile(&mut self.0).parse(input, from) {
+            Ok((to, _)) if to == from => Err((from, Error::TakeWhile1)),
+            otherwise => otherwise,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Capture<P>(pub P);
+
+impl<I, P> Parser<I> for Capture<P>
+where
+    I: Input,
+    P: Parser<I>,
+{
+    type Output = I::Slice;
+    type Error = P::Error;
+
+    #[inline(always)]
+    fn parse(&mut self, input: I, from: usize) -> Result<Self::Output, Self::Error> {
+        let (to, _) = self.0.parse(input, from)?;
+        Ok((to, input.slice(from, to)))
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SkipUntil<P>(pub P);
+
+impl<I, P> Parser<I> for SkipUntil<P>
+where
+    I: Input,
+    P: Parser<I>,
+{
+    type Output = I::Slice;
+    type Error = P::Error;
+
+    #[inline(always)]
+    fn parse(&mut self, input: I, from: usize) -> Result<Self::Output, Self::Error> {
+        let mut to = from;
+        while let Some((_, next)) = input.token(to) {
+            if self.0.parse(input, to).is_ok() {
+                break;
+            }
+            to = next;
+        }
+        Ok((to, input.slice(from, to)))
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Any;
+
+impl<I> Parser<I> for Any
+where
+    I: Input,
+{
+    type Output = I::Token;
+    type Error = Error<'static>;
+
+    #[inline(always)]
+    fn parse(&mut self, input: I, from: usize) -> Result<Self::Output, Self::Error> {
+        match input.token(from) {
+            Some((token, to)) => Ok((to, token)),
+            None if !input.is_complete() => incomplete(input.len(), Some(from + 1)),
+            None => Err((from, Error::Any)),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Peek;
+
+impl<I> Parser<I> for Peek
+where
+    I: Input,
+{
+    type Output = I::Token;
+    type Error = Error<'static>;
+
+    #[inline(always)]
+    fn parse(&mut self, input: I, from: usize) -> Result<Self::Output, Self::Error> {
+        match input.token(from) {
+            Some((token, _)) => Ok((from, token)),
+            None if !input.is_complete() => incomplete(input.len(), Some(from + 1)),
+            None => Err((from, Error::Peek)),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Take(pub usize);
+
+impl<I> Parser<I> for Take
+where
+    I: Input,
+{
+    type Output = I::Slice;
+    type Error = Error<'static>;
+
+    #[inline(always)]
+    fn parse(&mut self, input: I, from: usize) -> Result<Self::Output, Self::Error> {
+        match input.advance(from, self.0) {
+            Some(to) => Ok((to, input.slice(from, to))),
+            None if !input.is_complete() => {
+                incomplete(input.len(), Some((from + self.0).max(input.len() + 1)))
+            }
+            None => Err((from, Error::Take(self.0))),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct End;
+
+impl<I> Parser<I> for End
+where
+    I: Input,
+{
+    type Output = ();
+    type Error = Error<'static>;
+
+    #[inline(always)]
+    fn parse(&mut self, input: I, from: usize) -> Result<Self::Output, Self::Error> {
+        if from >= input.len() && !input.is_complete() {
+            incomplete(input.len(), None)
+        } else if input.len() == from {
+            Ok((from, ()))
+        } else {
+            Err((from, Error::End))
+        }
+    }
+}
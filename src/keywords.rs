@@ -0,0 +1,203 @@
+use std::marker::PhantomData;
+
+use crate::error::Error;
+use crate::{Parser, Result};
+
+pub trait Literal<'tmp>: Copy {
+    fn bytes(self) -> &'tmp [u8];
+    fn error(self) -> Error<'tmp>;
+}
+
+impl<'tmp> Literal<'tmp> for &'tmp str {
+    #[inline]
+    fn bytes(self) -> &'tmp [u8] {
+        self.as_bytes()
+    }
+
+    #[inline]
+    fn error(self) -> Error<'tmp> {
+        Error::Str(self)
+    }
+}
+
+impl<'tmp> Literal<'tmp> for &'tmp [u8] {
+    #[inline]
+    fn bytes(self) -> &'tmp [u8] {
+        self
+    }
+
+    #[inline]
+    fn error(self) -> Error<'tmp> {
+        Error::Bytes(self)
+    }
+}
+
+impl<'tmp, const N: usize> Literal<'tmp> for &'tmp [u8; N] {
+    #[inline]
+    fn bytes(self) -> &'tmp [u8] {
+        self
+    }
+
+    #[inline]
+    fn error(self) -> Error<'tmp> {
+        Error::Bytes(self)
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+struct Node {
+    value: Option<usize>,
+    // Sorted by byte.
+    children: Vec<(u8, usize)>,
+}
+
+// Matches the longest of a set of literals in a single pass over the input, using a byte trie
+// built up front. Adding the same literal twice keeps the later value. `&str` literals match `&str`
+// input and byte literals match `&[u8]` input.
+#[derive(Clone, Debug)]
+pub struct Keywords<'tmp, T, L> {
+    nodes: Vec<Node>,
+    values: Vec<T>,
+    expected: Error<'tmp>,
+    literal: PhantomData<L>,
+}
+
+impl<'tmp, L> Keywords<'tmp, L, L>
+where
+    L: Literal<'tmp>,
+{
+    #[inline]
+    pub fn new<I>(literals: I) -> Self
+    where
+        I: IntoIterator<Item = L>,
+    {
+        Keywords::with_values(literals.into_iter().map(|literal| (literal, literal)))
+    }
+}
+
+impl<'tmp, T, L> Keywords<'tmp, T, L>
+where
+    L: Literal<'tmp>,
+{
+    pub fn with_values<I>(literals: I) -> Self
+    where
+        I: IntoIterator<Item = (L, T)>,
+    {
+        let mut nodes = vec![Node::default()];
+        let mut values = Vec::new();
+        let mut expected = Vec::new();
+        for (literal, value) in literals {
+            let mut node = 0;
+            for &byte in literal.bytes() {
+                node = match nodes[node]
+                    .children
+                    .binary_search_by_key(&byte, |&(b, _)| b)
+                {
+                    Ok(index) => nodes[node].children[index].1,
+                    Err(index) => {
+                        nodes.push(Node::default());
+                        let child = nodes.len() - 1;
+                        nodes[node].children.insert(index, (byte, child));
+                        child
+                    }
+                };
+            }
+            match nodes[node].value {
+                Some(index) => values[index] = value,
+                None => {
+                    nodes[node].value = Some(values.len());
+                    values.push(value);
+                    expected.push(literal.error());
+                }
+            }
+        }
+        let expected = if expected.len() == 1 {
+            expected.pop().unwrap()
+        } else {
+            Error::Expected(expected)
+        };
+        Keywords {
+            nodes,
+            values,
+            expected,
+            literal: PhantomData,
+        }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    // The length and value index of the longest literal `bytes` starts with.
+    #[inline]
+    fn longest(&self, bytes: &[u8]) -> Option<(usize, usize)> {
+        let mut node = &self.nodes[0];
+        let mut longest = node.value.map(|index| (0, index));
+        for (len, byte) in bytes.iter().enumerate() {
+            match node.children.binary_search_by_key(byte, |&(b, _)| b) {
+                Ok(index) => node = &self.nodes[node.children[index].1],
+                Err(_) => break,
+            }
+            if let Some(index) = node.value {
+                longest = Some((len + 1, index));
+            }
+        }
+        longest
+    }
+
+    #[inline]
+    fn find(&self, input: &[u8], from: usize) -> Result<T, Error<'tmp>>
+    where
+        T: Clone,
+    {
+        match self.longest(&input[from..]) {
+            Some((len, index)) => Ok((from + len, self.values[index].clone())),
+            None => Err((from, self.expected.clone())),
+        }
+    }
+}
+
+impl<'a, 'tmp, T> Parser<&'a str> for Keywords<'tmp, T, &'tmp str>
+where
+    T: Clone,
+{
+    type Output = T;
+    type Error = Error<'tmp>;
+
+    #[inline]
+    fn parse(&mut self, input: &'a str, from: usize) -> Result<Self::Output, Self::Error> {
+        self.find(input.as_bytes(), from)
+    }
+}
+
+impl<'a, 'tmp, T> Parser<&'a [u8]> for Keywords<'tmp, T, &'tmp [u8]>
+where
+    T: Clone,
+{
+    type Output = T;
+    type Error = Error<'tmp>;
+
+    #[inline]
+    fn parse(&mut self, input: &'a [u8], from: usize) -> Result<Self::Output, Self::Error> {
+        self.find(input, from)
+    }
+}
+
+impl<'a, 'tmp, T, const N: usize> Parser<&'a [u8]> for Keywords<'tmp, T, &'tmp [u8; N]>
+where
+    T: Clone,
+{
+    type Output = T;
+    type Error = Error<'tmp>;
+
+    #[inline]
+    fn parse(&mut self, input: &'a [u8], from: usize) -> Result<Self::Output, Self::Error> {
+        self.find(input, from)
+    }
+}
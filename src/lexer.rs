@@ -0,0 +1,128 @@
+use crate::error::{Error, Shown};
+use crate::keywords::Keywords;
+use crate::span::Span;
+use crate::Parser;
+
+type Rule<'r> = Box<dyn FnMut(&str, usize) -> Option<usize> + 'r>;
+
+// Splits a `str` into `(Span, K, &str)` tokens. At every offset all rules are tried and the longest
+// match wins, with ties going to the rule registered first; matches of `skip` rules are dropped.
+pub struct Lexer<'r, K> {
+    rules: Vec<(Option<K>, Rule<'r>)>,
+}
+
+impl<'r, K> Lexer<'r, K>
+where
+    K: Clone,
+{
+    #[inline]
+    pub fn new() -> Self {
+        Lexer { rules: Vec::new() }
+    }
+
+    #[inline]
+    pub fn token<P>(self, kind: K, parser: P) -> Self
+    where
+        P: for<'a> Parser<&'a str> + 'r,
+    {
+        self.rule(Some(kind), parser)
+    }
+
+    #[inline]
+    pub fn keywords<I>(self, kind: K, keywords: I) -> Self
+    where
+        I: IntoIterator<Item = &'r str>,
+    {
+        self.rule(Some(kind), Keywords::new(keywords))
+    }
+
+    #[inline]
+    pub fn skip<P>(self, parser: P) -> Self
+    where
+        P: for<'a> Parser<&'a str> + 'r,
+    {
+        self.rule(None, parser)
+    }
+
+    fn rule<P>(mut self, kind: Option<K>, mut parser: P) -> Self
+    where
+        P: for<'a> Parser<&'a str> + 'r,
+    {
+        let rule = move |input: &str, from: usize| parser.parse(input, from).ok().map(|(to, _)| to);
+        self.rules.push((kind, Box::new(rule)));
+        self
+    }
+
+    #[inline]
+    pub fn tokens<'l, 'a>(&'l mut self, input: &'a str) -> Tokens<'l, 'a, 'r, K> {
+        Tokens {
+            lexer: self,
+            input,
+            from: 0,
+            done: false,
+        }
+    }
+}
+
+impl<'r, K> Default for Lexer<'r, K>
+where
+    K: Clone,
+{
+    #[inline]
+    fn default() -> Self {
+        Lexer::new()
+    }
+}
+
+pub struct Tokens<'l, 'a, 'r, K> {
+    lexer: &'l mut Lexer<'r, K>,
+    input: &'a str,
+    from: usize,
+    done: bool,
+}
+
+impl<'l, 'a, 'r, K> Tokens<'l, 'a, 'r, K> {
+    #[inline]
+    pub fn offset(&self) -> usize {
+        self.from
+    }
+}
+
+impl<'l, 'a, 'r, K> Iterator for Tokens<'l, 'a, 'r, K>
+where
+    K: Clone,
+{
+    type Item = Result<(Span, K, &'a str), (usize, Error<'static>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done && self.from < self.input.len() {
+            let from = self.from;
+            let mut longest = None;
+            for (index, (_, rule)) in self.lexer.rules.iter_mut().enumerate() {
+                if let Some(to) = rule(self.input, from) {
+                    if to > longest.map_or(from, |(to, _)| to) {
+                        longest = Some((to, index));
+                    }
+                }
+            }
+
+            match longest {
+                Some((to, index)) => {
+                    self.from = to;
+                    if let Some(kind) = &self.lexer.rules[index].0 {
+                        let span = Span::new(from, to);
+                        return Some(Ok((span, kind.clone(), &self.input[from..to])));
+                    }
+                }
+                None => {
+                    self.done = true;
+                    let found = self.input[from..].chars().next().map(Shown::Char);
+                    return Some(Err((from, Error::Token(None, found))));
+                }
+            }
+        }
+        None
+    }
+}
+
+impl<'l, 'a, 'r, K> std::iter::FusedIterator for Tokens<'l, 'a, 'r, K> where K: Clone {}
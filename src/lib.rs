@@ -1,9 +1,36 @@
 mod mac;
+mod scan;
+
+use std::borrow::Cow;
+
+use error::{Error, Incomplete, Merge};
+use memo::{Grow, LeftRec, Memo, Memoize};
+use recover::{Errors, Missing, Recover, Rewind};
+use span::{IntoSpanned, WithSpan};
+
+#[cfg(feature = "binary")]
+pub use munch_macros::BinaryParse;
+#[cfg(feature = "macros")]
+pub use munch_macros::{peg, Parse};
 
 pub mod ascii;
 pub mod byte;
+pub mod class;
 pub mod error;
+pub mod input;
+pub mod keywords;
+pub mod lexer;
+pub mod location;
+pub mod memo;
+pub mod num;
+pub mod partial;
+pub mod pratt;
+pub mod recover;
+pub mod report;
+pub mod span;
 pub mod str;
+pub mod token;
+pub mod window;
 
 pub type Result<Output, Error> = std::result::Result<(usize, Output), (usize, Error)>;
 
@@ -22,7 +49,7 @@ pub trait Parser<Input> {
     }
 
     #[inline(always)]
-    fn by_ref(&mut self) -> P<ByRef<Self>>
+    fn by_ref(&mut self) -> P<ByRef<'_, Self>>
     where
         Self: Sized,
     {
@@ -56,6 +83,8 @@ pub trait Parser<Input> {
         P(SkipAnd(self, b))
     }
 
+    // Only the last alternative's error is kept when every alternative fails at `from`; `alt`
+    // merges them all instead, e.g. into "expected 'a', 'b' or \"cd\"".
     #[inline(always)]
     fn or<B>(self, b: B) -> P<Or<Self, B>>
     where
@@ -65,6 +94,16 @@ pub trait Parser<Input> {
         P(Or(self, b))
     }
 
+    #[inline(always)]
+    fn alt<B>(self, b: B) -> P<Alt<Self, B>>
+    where
+        Self: Sized,
+        B: Parser<Input, Output = Self::Output, Error = Self::Error>,
+        Self::Error: Merge,
+    {
+        P(Alt(self, b))
+    }
+
     #[inline(always)]
     fn map<F, Output>(self, f: F) -> P<Map<Self, F>>
     where
@@ -83,6 +122,47 @@ pub trait Parser<Input> {
         P(MapErr(self, f))
     }
 
+    #[inline(always)]
+    fn label(self, label: &str) -> P<Label<'_, Self>>
+    where
+        Self: Sized,
+    {
+        P(Label(self, label))
+    }
+
+    #[inline(always)]
+    fn context<'a>(self, context: impl Into<Cow<'a, str>>) -> P<Context<'a, Self>>
+    where
+        Self: Sized,
+    {
+        P(Context(self, context.into()))
+    }
+
+    #[inline(always)]
+    fn context_with<F>(self, f: F) -> P<ContextWith<Self, F>>
+    where
+        Self: Sized,
+        F: FnMut() -> String,
+    {
+        P(ContextWith(self, f))
+    }
+
+    #[inline(always)]
+    fn with_span(self) -> P<WithSpan<Self>>
+    where
+        Self: Sized,
+    {
+        P(WithSpan(self))
+    }
+
+    #[inline(always)]
+    fn spanned(self) -> P<IntoSpanned<Self>>
+    where
+        Self: Sized,
+    {
+        P(IntoSpanned(self))
+    }
+
     #[inline(always)]
     fn and_then<F, Output>(self, f: F) -> P<AndThen<Self, F>>
     where
@@ -110,6 +190,60 @@ pub trait Parser<Input> {
         P(Bind(self, f))
     }
 
+    #[inline(always)]
+    fn recover<B, F>(
+        self,
+        errors: &Errors<Self::Error>,
+        b: B,
+        f: F,
+    ) -> P<Recover<'_, Self, B, F, Self::Error>>
+    where
+        Self: Sized,
+        B: Parser<Input>,
+        F: FnMut(B::Output) -> Self::Output,
+    {
+        P(Recover(self, errors, b, f))
+    }
+
+    #[inline(always)]
+    fn missing<F>(self, errors: &Errors<Self::Error>, f: F) -> P<Missing<'_, Self, F, Self::Error>>
+    where
+        Self: Sized,
+        F: FnMut() -> Self::Output,
+    {
+        P(Missing(self, errors, f))
+    }
+
+    #[inline(always)]
+    fn rewind<E>(self, errors: &Errors<E>) -> P<Rewind<'_, Self, E>>
+    where
+        Self: Sized,
+    {
+        P(Rewind(self, errors))
+    }
+
+    #[inline(always)]
+    fn memoize(
+        self,
+        memo: &Memo<Self::Output, Self::Error>,
+    ) -> P<Memoize<'_, Self, Self::Output, Self::Error>>
+    where
+        Self: Sized,
+    {
+        P(Memoize(self, memo))
+    }
+
+    #[inline(always)]
+    fn grow(
+        self,
+        table: &LeftRec<Self::Output, Self::Error>,
+    ) -> P<Grow<'_, Self, Self::Output, Self::Error>>
+    where
+        Self: Sized,
+    {
+        P(Grow(self, table))
+    }
+
     #[inline(always)]
     fn repeat<R>(self, range: R) -> P<Repeat<Self, R>>
     where
@@ -118,6 +252,19 @@ pub trait Parser<Input> {
     {
         P(Repeat(self, range))
     }
+
+    #[inline(always)]
+    fn iter(self, input: Input) -> ParseIter<Self, Input>
+    where
+        Self: Sized,
+    {
+        ParseIter {
+            parser: self,
+            input,
+            from: 0,
+            done: false,
+        }
+    }
 }
 
 impl<F, Input, Output, Error> Parser<Input> for F
@@ -133,6 +280,11 @@ where
     }
 }
 
+// Types with a canonical parser, e.g. from `#[derive(Parse)]`.
+pub trait Parse<Input>: Sized {
+    fn parse(input: Input, from: usize) -> Result<Self, Error<'static>>;
+}
+
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct P<A>(pub A);
 
@@ -165,6 +317,7 @@ where
     }
 }
 
+// Like `or`, so it keeps only the last error; use `alt` to merge them.
 impl<A, B> std::ops::BitOr<B> for P<A> {
     type Output = P<Or<A, B>>;
 
@@ -294,6 +447,65 @@ where
     }
 }
 
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Alt<A, B>(A, B);
+
+impl<A, B, Input> Parser<Input> for Alt<A, B>
+where
+    A: Parser<Input>,
+    B: Parser<Input, Output = A::Output, Error = A::Error>,
+    A::Error: Merge,
+    Input: Copy,
+{
+    type Output = A::Output;
+    type Error = A::Error;
+
+    #[inline(always)]
+    fn parse(&mut self, input: Input, from: usize) -> Result<Self::Output, Self::Error> {
+        match self.0.parse(input, from) {
+            Ok((from, output)) => Ok((from, output)),
+            Err((from2, error)) => {
+                if from == from2 {
+                    match self.1.parse(input, from) {
+                        Err((from2, error2)) if from == from2 => Err((from, error.merge(error2))),
+                        otherwise => otherwise,
+                    }
+                } else {
+                    Err((from2, error))
+                }
+            }
+        }
+    }
+}
+
+// Ordered choice as in PEGs: `B` is tried from `from` even if `A` consumed input before failing.
+// If both fail, the failure that got further wins, and the two are merged on a tie.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Choice<A, B>(pub A, pub B);
+
+impl<A, B, Input> Parser<Input> for Choice<A, B>
+where
+    A: Parser<Input>,
+    B: Parser<Input, Output = A::Output, Error = A::Error>,
+    A::Error: Merge,
+    Input: Copy,
+{
+    type Output = A::Output;
+    type Error = A::Error;
+
+    #[inline(always)]
+    fn parse(&mut self, input: Input, from: usize) -> Result<Self::Output, Self::Error> {
+        match self.0.parse(input, from) {
+            Ok(ok) => Ok(ok),
+            Err((from1, error1)) => match self.1.parse(input, from) {
+                Err((from2, error2)) if from1 == from2 => Err((from1, error1.merge(error2))),
+                Err((from2, _)) if from1 > from2 => Err((from1, error1)),
+                otherwise => otherwise,
+            },
+        }
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct Try<A>(pub A);
 
@@ -312,6 +524,70 @@ where
     }
 }
 
+// Lookahead never consumes input. A failure is passed through as is, so wrap it in `Try` to
+// backtrack from one that got past `from`; incomplete input keeps its offset either way.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Lookahead<A>(pub A);
+
+impl<A, Input> Parser<Input> for Lookahead<A>
+where
+    A: Parser<Input>,
+{
+    type Output = A::Output;
+    type Error = A::Error;
+
+    #[inline(always)]
+    fn parse(&mut self, input: Input, from: usize) -> Result<Self::Output, Self::Error> {
+        match self.0.parse(input, from) {
+            Ok((_, output)) => Ok((from, output)),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+// Succeeds without consuming input if `A` fails, and fails at `from` with `Error::Not` otherwise.
+// An incomplete failure keeps its offset so that the caller knows to read more.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Not<A>(pub A);
+
+impl<'e, A, Input> Parser<Input> for Not<A>
+where
+    A: Parser<Input, Error = Error<'e>>,
+{
+    type Output = ();
+    type Error = Error<'e>;
+
+    #[inline(always)]
+    fn parse(&mut self, input: Input, from: usize) -> Result<Self::Output, Self::Error> {
+        match self.0.parse(input, from) {
+            Ok(_) => Err((from, Error::Not)),
+            Err((from2, error)) if error.needed().is_some() => Err((from2, error)),
+            Err(_) => Ok((from, ())),
+        }
+    }
+}
+
+// `Not` for any error type: a match of `A` fails with the error `F` builds from its output.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct NotWith<A, F>(pub A, pub F);
+
+impl<A, F, Input> Parser<Input> for NotWith<A, F>
+where
+    A: Parser<Input>,
+    F: FnMut(A::Output) -> A::Error,
+{
+    type Output = ();
+    type Error = A::Error;
+
+    #[inline(always)]
+    fn parse(&mut self, input: Input, from: usize) -> Result<Self::Output, Self::Error> {
+        match self.0.parse(input, from) {
+            Ok((_, output)) => Err((from, self.1(output))),
+            Err(_) => Ok((from, ())),
+        }
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct Map<A, F>(A, F);
 
@@ -350,6 +626,73 @@ where
     }
 }
 
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Label<'a, A>(A, &'a str);
+
+impl<'a, 'e: 'a, A, Input> Parser<Input> for Label<'a, A>
+where
+    A: Parser<Input, Error = Error<'e>>,
+{
+    type Output = A::Output;
+    type Error = Error<'a>;
+
+    #[inline(always)]
+    fn parse(&mut self, input: Input, from: usize) -> Result<Self::Output, Self::Error> {
+        match self.0.parse(input, from) {
+            Ok((from, output)) => Ok((from, output)),
+            Err((from2, _)) if from == from2 => Err((from, Error::Label(self.1))),
+            Err((from2, error)) => Err((from2, error)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Context<'a, A>(A, Cow<'a, str>);
+
+impl<'a, 'e: 'a, A, Input> Parser<Input> for Context<'a, A>
+where
+    A: Parser<Input, Error = Error<'e>>,
+{
+    type Output = A::Output;
+    type Error = Error<'a>;
+
+    #[inline(always)]
+    fn parse(&mut self, input: Input, from: usize) -> Result<Self::Output, Self::Error> {
+        match self.0.parse(input, from) {
+            Ok((from, output)) => Ok((from, output)),
+            Err((from2, error)) if from == from2 => Err((from2, error)),
+            Err((from2, error)) => {
+                Err((from2, Error::Context(self.1.clone(), from, Box::new(error))))
+            }
+        }
+    }
+}
+
+// Like `Context`, but the frame is only built when there's an error to wrap.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ContextWith<A, F>(A, F);
+
+impl<'e, A, F, Input> Parser<Input> for ContextWith<A, F>
+where
+    A: Parser<Input, Error = Error<'e>>,
+    F: FnMut() -> String,
+{
+    type Output = A::Output;
+    type Error = Error<'e>;
+
+    #[inline(always)]
+    fn parse(&mut self, input: Input, from: usize) -> Result<Self::Output, Self::Error> {
+        match self.0.parse(input, from) {
+            Ok((from, output)) => Ok((from, output)),
+            Err((from2, error)) if from == from2 => Err((from2, error)),
+            Err((from2, error)) => Err((
+                from2,
+                Error::Context((self.1)().into(), from, Box::new(error)),
+            )),
+        }
+    }
+}
+
 macro_rules! tuple_impl {
     ($(($head:ident $($tail:ident)*),)+) => {$(
         impl<$head $(,$tail)*, Input> Parser<Input> for ($head, $($tail),*)
@@ -707,6 +1050,68 @@ where
     }
 }
 
+// Yields items one at a time until the parser fails, yielding the failure once as the last item.
+// A success that consumed nothing would repeat forever, so it ends the iteration with `Stalled`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ParseIter<A, Input> {
+    parser: A,
+    input: Input,
+    from: usize,
+    done: bool,
+}
+
+impl<A, Input> ParseIter<A, Input> {
+    #[inline(always)]
+    pub fn offset(&self) -> usize {
+        self.from
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum IterError<E> {
+    Parse(usize, E),
+    Stalled(usize),
+}
+
+// Input that runs out between items ends the iteration; a parser failing anywhere else, even right
+// at the end of an item cut short, yields its error once.
+impl<A, Input> Iterator for ParseIter<A, Input>
+where
+    A: Parser<Input>,
+    Input: input::Input,
+{
+    type Item = std::result::Result<A::Output, IterError<A::Error>>;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done || (self.from >= self.input.len() && self.input.is_complete()) {
+            return None;
+        }
+
+        match self.parser.parse(self.input, self.from) {
+            Ok((from, _)) if from == self.from => {
+                self.done = true;
+                Some(Err(IterError::Stalled(from)))
+            }
+            Ok((from, output)) => {
+                self.from = from;
+                Some(Ok(output))
+            }
+            Err((from, error)) => {
+                self.done = true;
+                Some(Err(IterError::Parse(from, error)))
+            }
+        }
+    }
+}
+
+impl<A, Input> std::iter::FusedIterator for ParseIter<A, Input>
+where
+    A: Parser<Input>,
+    Input: input::Input,
+{
+}
+
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct Join<A, B, R: Range>(A, B, R);
 
@@ -0,0 +1,87 @@
+use std::fmt;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Location {
+    pub offset: usize,
+    pub line: usize,
+    pub column: usize,
+    pub utf16_column: usize,
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}", self.line + 1, self.column + 1)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LineIndex<'a> {
+    input: &'a [u8],
+    lines: Vec<usize>,
+}
+
+impl<'a> LineIndex<'a> {
+    pub fn new<Input>(input: &'a Input) -> Self
+    where
+        Input: AsRef<[u8]> + ?Sized,
+    {
+        let input = input.as_ref();
+        let mut lines = vec![0];
+        lines.extend(
+            input
+                .iter()
+                .enumerate()
+                .filter(|&(_, &byte)| byte == b'\n')
+                .map(|(offset, _)| offset + 1),
+        );
+        LineIndex { input, lines }
+    }
+
+    #[inline]
+    pub fn lines(&self) -> usize {
+        self.lines.len()
+    }
+
+    #[inline]
+    pub fn line_start(&self, line: usize) -> Option<usize> {
+        self.lines.get(line).copied()
+    }
+
+    pub fn line(&self, line: usize) -> Option<&'a [u8]> {
+        let from = *self.lines.get(line)?;
+        let to = match self.lines.get(line + 1) {
+            Some(&to) => to - 1,
+            None => self.input.len(),
+        };
+        let line = &self.input[from..to];
+        if line.ends_with(b"\r") {
+            Some(&line[..line.len() - 1])
+        } else {
+            Some(line)
+        }
+    }
+
+    pub fn location(&self, offset: usize) -> Location {
+        let offset = offset.min(self.input.len());
+        let line = match self.lines.binary_search(&offset) {
+            Ok(line) => line,
+            Err(line) => line - 1,
+        };
+        let prefix = &self.input[self.lines[line]..offset];
+        // Count UTF-8 leading bytes, so a multi-byte character counts as one column; characters
+        // outside the BMP (4-byte sequences) take two UTF-16 code units.
+        let column = prefix.iter().filter(|&&byte| byte & 0xC0 != 0x80).count();
+        let utf16_column = column + prefix.iter().filter(|&&byte| byte >= 0xF0).count();
+        Location {
+            offset,
+            line,
+            column,
+            utf16_column,
+        }
+    }
+
+    #[inline]
+    pub fn locate<Error>(&self, (offset, error): (usize, Error)) -> (Location, Error) {
+        (self.location(offset), error)
+    }
+}
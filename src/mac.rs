@@ -30,3 +30,13 @@ macro_rules! muncher {
         $crate::P(|input, from| muncher!(@internal input from $($tt)+))
     }
 }
+
+#[macro_export]
+macro_rules! select {
+    ($($pat:pat $(if $guard:expr)? => $output:expr),+ $(,)?) => {
+        $crate::token::Select(|token: &_| match token {
+            $($pat $(if $guard)? => ::std::option::Option::Some($output),)+
+            _ => ::std::option::Option::None,
+        })
+    };
+}
@@ -0,0 +1,253 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use crate::{Parser, Result};
+
+// A table caches a single rule: results are keyed by offset alone, so sharing one table between
+// different parsers would hand one rule's result to another. Use a table per memoized rule, and
+// `clear` them all between inputs.
+#[derive(Debug, Default)]
+pub struct Memo<O, E>(RefCell<HashMap<usize, Result<O, E>>>);
+
+impl<O, E> Memo<O, E> {
+    #[inline]
+    pub fn new() -> Self {
+        Memo(RefCell::new(HashMap::new()))
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.borrow().len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.borrow().is_empty()
+    }
+
+    #[inline]
+    pub fn clear(&self) {
+        self.0.borrow_mut().clear();
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct Memoize<'m, A, O, E>(pub(crate) A, pub(crate) &'m Memo<O, E>);
+
+impl<'m, A, O, E, Input> Parser<Input> for Memoize<'m, A, O, E>
+where
+    A: Parser<Input, Output = O, Error = E>,
+    O: Clone,
+    E: Clone,
+{
+    type Output = O;
+    type Error = E;
+
+    #[inline]
+    fn parse(&mut self, input: Input, from: usize) -> Result<Self::Output, Self::Error> {
+        if let Some(result) = (self.1).0.borrow().get(&from) {
+            return result.clone();
+        }
+        let result = self.0.parse(input, from);
+        (self.1).0.borrow_mut().insert(from, result.clone());
+        result
+    }
+}
+
+// Seed-growing left recursion (Warth et al., "Packrat Parsers Can Support Left Recursion").
+// Every rule has its own table, and rules are identified by the address of their table. The
+// bookkeeping shared between the rules of an indirectly left-recursive cycle (the stack of rules
+// being parsed and the heads being grown) lives in a thread local, since the tables have
+// different output types and can't hold it.
+#[derive(Debug, Default)]
+pub struct LeftRec<O, E>(RefCell<HashMap<usize, Entry<O, E>>>);
+
+#[derive(Clone, Debug)]
+enum Entry<O, E> {
+    // The rule is still being parsed at this offset; recursive calls get the seed.
+    Rec {
+        frame: usize,
+        involved: bool,
+        seed: Result<O, E>,
+    },
+    Done(Result<O, E>),
+}
+
+impl<O, E> LeftRec<O, E> {
+    #[inline]
+    pub fn new() -> Self {
+        LeftRec(RefCell::new(HashMap::new()))
+    }
+
+    #[inline]
+    pub fn clear(&self) {
+        self.0.borrow_mut().clear();
+    }
+}
+
+struct Frame {
+    rule: usize,
+    head: Option<usize>,
+}
+
+struct Head {
+    rule: usize,
+    involved: Vec<usize>,
+    eval: Vec<usize>,
+}
+
+#[derive(Default)]
+struct State {
+    stack: Vec<Frame>,
+    heads: Vec<Head>,
+    growing: HashMap<usize, usize>,
+}
+
+thread_local! {
+    static STATE: RefCell<State> = RefCell::default();
+}
+
+#[inline]
+fn state<T>(f: impl FnOnce(&mut State) -> T) -> T {
+    STATE.with(|state| f(&mut state.borrow_mut()))
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct Grow<'t, A, O, E>(pub(crate) A, pub(crate) &'t LeftRec<O, E>);
+
+impl<'t, A, O, E, Input> Parser<Input> for Grow<'t, A, O, E>
+where
+    A: Parser<Input, Output = O, Error = E>,
+    O: Clone,
+    E: Clone + Default,
+    Input: Copy,
+{
+    type Output = O;
+    type Error = E;
+
+    fn parse(&mut self, input: Input, from: usize) -> Result<Self::Output, Self::Error> {
+        let rule = self.1 as *const LeftRec<O, E> as usize;
+        let table = &(self.1).0;
+
+        // While a head is growing at this offset, rules outside its cycle fail, and each rule
+        // inside it is re-parsed once per round instead of answering from its table.
+        if let Some((involved, eval)) = state(|state| {
+            let head = &mut state.heads[*state.growing.get(&from)?];
+            let involved = head.rule == rule || head.involved.contains(&rule);
+            let eval = head.eval.iter().position(|&r| r == rule);
+            if let Some(index) = eval {
+                head.eval.swap_remove(index);
+            }
+            Some((involved, eval.is_some()))
+        }) {
+            if !involved && !table.borrow().contains_key(&from) {
+                return Err((from, E::default()));
+            }
+            if eval {
+                let result = self.0.parse(input, from);
+                table.borrow_mut().insert(from, Entry::Done(result.clone()));
+                return result;
+            }
+        }
+
+        let entry = table.borrow().get(&from).cloned();
+        match entry {
+            Some(Entry::Done(result)) => return result,
+            Some(Entry::Rec {
+                frame,
+                involved,
+                seed,
+            }) => {
+                // Left recursion: make this rule the head of every rule parsed since it started.
+                if !involved {
+                    state(|state| {
+                        let head = match state.stack[frame].head {
+                            Some(head) => head,
+                            None => {
+                                state.heads.push(Head {
+                                    rule,
+                                    involved: Vec::new(),
+                                    eval: Vec::new(),
+                                });
+                                state.stack[frame].head = Some(state.heads.len() - 1);
+                                state.heads.len() - 1
+                            }
+                        };
+                        let mut index = state.stack.len() - 1;
+                        while state.stack[index].head != Some(head) {
+                            state.stack[index].head = Some(head);
+                            let rule = state.stack[index].rule;
+                            state.heads[head].involved.push(rule);
+                            index -= 1;
+                        }
+                    });
+                }
+                return seed;
+            }
+            None => {}
+        }
+
+        let frame = state(|state| {
+            state.stack.push(Frame { rule, head: None });
+            state.stack.len() - 1
+        });
+        let seed = Err((from, E::default()));
+        table.borrow_mut().insert(
+            from,
+            Entry::Rec {
+                frame,
+                involved: false,
+                seed,
+            },
+        );
+        let result = self.0.parse(input, from);
+        let head = state(|state| state.stack.pop().and_then(|frame| frame.head));
+
+        let result = match head {
+            None => {
+                table.borrow_mut().insert(from, Entry::Done(result.clone()));
+                result
+            }
+            // Part of a cycle headed by another rule, which will grow it.
+            Some(head) if state(|state| state.heads[head].rule) != rule => {
+                table.borrow_mut().insert(
+                    from,
+                    Entry::Rec {
+                        frame,
+                        involved: true,
+                        seed: result.clone(),
+                    },
+                );
+                result
+            }
+            Some(head) => {
+                table.borrow_mut().insert(from, Entry::Done(result.clone()));
+                let mut seed = result;
+                if seed.is_ok() {
+                    // Keep re-parsing with the previous result as the seed for as long as the
+                    // match grows.
+                    state(|state| state.growing.insert(from, head));
+                    loop {
+                        state(|state| state.heads[head].eval = state.heads[head].involved.clone());
+                        let result = self.0.parse(input, from);
+                        match (&result, &seed) {
+                            (Ok((to, _)), Ok((seed_to, _))) if to > seed_to => {}
+                            _ => break,
+                        }
+                        seed = result;
+                        table.borrow_mut().insert(from, Entry::Done(seed.clone()));
+                    }
+                    state(|state| state.growing.remove(&from));
+                }
+                seed
+            }
+        };
+
+        state(|state| {
+            if state.stack.is_empty() && state.growing.is_empty() {
+                state.heads.clear();
+            }
+        });
+        result
+    }
+}
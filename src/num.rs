@@ -0,0 +1,365 @@
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use crate::error::{Error, Number};
+use crate::{Parse, Parser, Result};
+
+pub use crate::error::Radix;
+
+impl Radix {
+    #[inline(always)]
+    fn value(self) -> u32 {
+        match self {
+            Radix::Binary => 2,
+            Radix::Octal => 8,
+            Radix::Decimal => 10,
+            Radix::Hexadecimal => 16,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Syntax {
+    Json,
+    Rust,
+    C,
+}
+
+pub trait Integral: Copy {
+    const NAME: &'static str;
+    const SIGNED: bool;
+    const ZERO: Self;
+
+    fn push(self, radix: u32, digit: u32, negative: bool) -> Option<Self>;
+}
+
+macro_rules! integral {
+    ($($ty:ident: $signed:expr,)+) => {
+        $(
+            impl Integral for $ty {
+                const NAME: &'static str = stringify!($ty);
+                const SIGNED: bool = $signed;
+                const ZERO: Self = 0;
+
+                #[inline(always)]
+                fn push(self, radix: u32, digit: u32, negative: bool) -> Option<Self> {
+                    let n = self.checked_mul(radix as $ty)?;
+                    // Accumulate negative numbers downwards so that `MIN` doesn't overflow.
+                    if negative {
+                        n.checked_sub(digit as $ty)
+                    } else {
+                        n.checked_add(digit as $ty)
+                    }
+                }
+            }
+
+            impl<'a> Parse<&'a str> for $ty {
+                #[inline(always)]
+                fn parse(input: &'a str, from: usize) -> Result<Self, Error<'static>> {
+                    Integer::new().parse(input, from)
+                }
+            }
+
+            impl<'a> Parse<&'a [u8]> for $ty {
+                #[inline(always)]
+                fn parse(input: &'a [u8], from: usize) -> Result<Self, Error<'static>> {
+                    Integer::new().parse(input, from)
+                }
+            }
+
+            #[inline(always)]
+            pub fn $ty() -> Integer<$ty> {
+                Integer::new()
+            }
+        )+
+    };
+}
+
+integral! {
+    u8: false,
+    u16: false,
+    u32: false,
+    u64: false,
+    u128: false,
+    usize: false,
+    i8: true,
+    i16: true,
+    i32: true,
+    i64: true,
+    i128: true,
+    isize: true,
+}
+
+pub trait Floating: FromStr + Copy {
+    const NAME: &'static str;
+
+    fn is_infinite(self) -> bool;
+}
+
+macro_rules! floating {
+    ($($ty:ident,)+) => {
+        $(
+            impl Floating for $ty {
+                const NAME: &'static str = stringify!($ty);
+
+                #[inline(always)]
+                fn is_infinite(self) -> bool {
+                    $ty::is_infinite(self)
+                }
+            }
+
+            impl<'a> Parse<&'a str> for $ty {
+                #[inline(always)]
+                fn parse(input: &'a str, from: usize) -> Result<Self, Error<'static>> {
+                    Float::new().parse(input, from)
+                }
+            }
+
+            impl<'a> Parse<&'a [u8]> for $ty {
+                #[inline(always)]
+                fn parse(input: &'a [u8], from: usize) -> Result<Self, Error<'static>> {
+                    Float::new().parse(input, from)
+                }
+            }
+
+            #[inline(always)]
+            pub fn $ty() -> Float<$ty> {
+                Float::new()
+            }
+        )+
+    };
+}
+
+floating! {
+    f32,
+    f64,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Integer<T> {
+    radix: Radix,
+    prefixed: bool,
+    underscores: bool,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Integer<T> {
+    #[inline(always)]
+    pub fn new() -> Self {
+        Integer {
+            radix: Radix::Decimal,
+            prefixed: false,
+            underscores: false,
+            marker: PhantomData,
+        }
+    }
+
+    #[inline(always)]
+    pub fn radix(self, radix: Radix) -> Self {
+        Integer { radix, ..self }
+    }
+
+    // Accept `0x`, `0o` and `0b` prefixes, falling back to `radix` without one.
+    #[inline(always)]
+    pub fn prefixed(self) -> Self {
+        Integer {
+            prefixed: true,
+            ..self
+        }
+    }
+
+    #[inline(always)]
+    pub fn underscores(self) -> Self {
+        Integer {
+            underscores: true,
+            ..self
+        }
+    }
+}
+
+impl<T> Default for Integer<T> {
+    #[inline(always)]
+    fn default() -> Self {
+        Integer::new()
+    }
+}
+
+impl<'a, T, Input> Parser<&'a Input> for Integer<T>
+where
+    T: Integral,
+    Input: AsRef<[u8]> + ?Sized,
+{
+    type Output = T;
+    type Error = Error<'static>;
+
+    fn parse(&mut self, input: &'a Input, from: usize) -> Result<Self::Output, Self::Error> {
+        let bytes = input.as_ref();
+        let mut i = from;
+
+        let negative = T::SIGNED && bytes.get(i) == Some(&b'-');
+        if negative {
+            i += 1;
+        }
+
+        let mut radix = self.radix;
+        let mut prefix = false;
+        if self.prefixed {
+            let r = match bytes.get(i..i + 2) {
+                Some(b"0x") | Some(b"0X") => Some(Radix::Hexadecimal),
+                Some(b"0o") | Some(b"0O") => Some(Radix::Octal),
+                Some(b"0b") | Some(b"0B") => Some(Radix::Binary),
+                _ => None,
+            };
+            if let Some(r) = r {
+                radix = r;
+                prefix = true;
+                i += 2;
+            }
+        }
+
+        let mut value = Some(T::ZERO);
+        let mut digits = 0;
+        while let Some(&byte) = bytes.get(i) {
+            if let Some(digit) = (byte as char).to_digit(radix.value()) {
+                value = value.and_then(|value| value.push(radix.value(), digit, negative));
+                digits += 1;
+            } else if !(byte == b'_' && self.underscores && (digits > 0 || prefix)) {
+                break;
+            }
+            i += 1;
+        }
+
+        match value {
+            _ if digits == 0 && prefix => Err((i, Error::Number(Number::Digit(radix)))),
+            _ if digits == 0 => Err((from, Error::Number(Number::Integer))),
+            Some(value) => Ok((i, value)),
+            None => Err((i, Error::Number(Number::Overflow(T::NAME)))),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Float<T> {
+    syntax: Syntax,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Float<T> {
+    #[inline(always)]
+    pub fn new() -> Self {
+        Float {
+            syntax: Syntax::Json,
+            marker: PhantomData,
+        }
+    }
+
+    #[inline(always)]
+    pub fn syntax(self, syntax: Syntax) -> Self {
+        Float { syntax, ..self }
+    }
+}
+
+impl<T> Default for Float<T> {
+    #[inline(always)]
+    fn default() -> Self {
+        Float::new()
+    }
+}
+
+impl<'a, T, Input> Parser<&'a Input> for Float<T>
+where
+    T: Floating,
+    Input: AsRef<[u8]> + ?Sized,
+{
+    type Output = T;
+    type Error = Error<'static>;
+
+    fn parse(&mut self, input: &'a Input, from: usize) -> Result<Self::Output, Self::Error> {
+        let bytes = input.as_ref();
+        let underscores = self.syntax == Syntax::Rust;
+        let digits = |from: usize| {
+            let mut to = from;
+            while let Some(&byte) = bytes.get(to) {
+                if !(byte.is_ascii_digit() || underscores && byte == b'_' && to > from) {
+                    break;
+                }
+                to += 1;
+            }
+            to
+        };
+        let is_digit = |i: usize| matches!(bytes.get(i), Some(byte) if byte.is_ascii_digit());
+
+        let mut i = from;
+        if bytes.get(i) == Some(&b'-') {
+            i += 1;
+        }
+
+        let start = i;
+        i = match self.syntax {
+            // JSON doesn't allow leading zeros, so `01` isn't a number at all.
+            Syntax::Json if bytes.get(i) == Some(&b'0') && is_digit(i + 1) => {
+                return Err((from, Error::Number(Number::Float)))
+            }
+            Syntax::Json if bytes.get(i) == Some(&b'0') => i + 1,
+            Syntax::Json | Syntax::Rust if !is_digit(i) => {
+                return Err((from, Error::Number(Number::Float)))
+            }
+            _ => digits(i),
+        };
+        let integer = i > start;
+
+        if bytes.get(i) == Some(&b'.') {
+            match self.syntax {
+                _ if is_digit(i + 1) => i = digits(i + 1),
+                Syntax::Json => {}
+                // `1.` is a float in Rust, but `1..2`, `1._x` and `1.abs()` aren't.
+                Syntax::Rust => match bytes.get(i + 1) {
+                    Some(&byte) if byte == b'.' || byte == b'_' || !byte.is_ascii() => {}
+                    Some(&byte) if byte.is_ascii_alphabetic() => {}
+                    _ => i += 1,
+                },
+                Syntax::C if integer => i += 1,
+                Syntax::C => {}
+            }
+        }
+
+        if i == start {
+            return Err((from, Error::Number(Number::Float)));
+        }
+
+        if let Some(b'e') | Some(b'E') = bytes.get(i) {
+            let mut j = i + 1;
+            if let Some(b'+') | Some(b'-') = bytes.get(j) {
+                j += 1;
+            }
+            let k = if underscores {
+                digits_after_underscores(bytes, j)
+            } else {
+                j
+            };
+            if is_digit(k) {
+                i = digits(k);
+            }
+        }
+
+        let text = std::str::from_utf8(&bytes[from..i]).unwrap();
+        let value = if text.contains('_') {
+            text.replace('_', "").parse()
+        } else {
+            text.parse()
+        };
+        match value {
+            Ok(value) if !T::is_infinite(value) => Ok((i, value)),
+            Ok(_) => Err((i, Error::Number(Number::Overflow(T::NAME)))),
+            Err(_) => Err((from, Error::Number(Number::Float))),
+        }
+    }
+}
+
+#[inline(always)]
+fn digits_after_underscores(bytes: &[u8], mut from: usize) -> usize {
+    while bytes.get(from) == Some(&b'_') {
+        from += 1;
+    }
+    from
+}
@@ -0,0 +1,216 @@
+use std::io::{self, Read};
+
+use crate::error::{Incomplete, Needed};
+use crate::input::Input;
+use crate::Parser;
+
+pub mod ascii;
+pub mod byte;
+pub mod str;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Partial<I> {
+    pub input: I,
+    pub complete: bool,
+}
+
+impl<I> Partial<I> {
+    #[inline]
+    pub fn new(input: I) -> Self {
+        Partial {
+            input,
+            complete: false,
+        }
+    }
+
+    #[inline]
+    pub fn complete(input: I) -> Self {
+        Partial {
+            input,
+            complete: true,
+        }
+    }
+}
+
+impl<I> Input for Partial<I>
+where
+    I: Input,
+{
+    type Token = I::Token;
+    type Slice = I::Slice;
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.input.len()
+    }
+
+    #[inline(always)]
+    fn token(&self, at: usize) -> Option<(Self::Token, usize)> {
+        self.input.token(at)
+    }
+
+    #[inline(always)]
+    fn slice(&self, from: usize, to: usize) -> Self::Slice {
+        self.input.slice(from, to)
+    }
+
+    #[inline(always)]
+    fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    #[inline(always)]
+    fn take_while<F>(&self, from: usize, f: F) -> usize
+    where
+        F: FnMut(Self::Token) -> bool,
+    {
+        self.input.take_while(from, f)
+    }
+
+    #[inline(always)]
+    fn advance(&self, from: usize, count: usize) -> Option<usize> {
+        self.input.advance(from, count)
+    }
+}
+
+// Like `crate::Try`, but a failure that needs more input keeps its offset, so `Optional`, `Or` and
+// `Repeat` still wait for the rest of the input instead of settling for an alternative.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Try<A>(pub A);
+
+impl<A, I> Parser<I> for Try<A>
+where
+    A: Parser<I>,
+    A::Error: Incomplete,
+{
+    type Output = A::Output;
+    type Error = A::Error;
+
+    #[inline(always)]
+    fn parse(&mut self, input: I, from: usize) -> crate::Result<Self::Output, Self::Error> {
+        match self.0.parse(input, from) {
+            Err((to, error)) if error.needed().is_some() => Err((to, error)),
+            Err((_, error)) => Err((from, error)),
+            otherwise => otherwise,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ReadError<E> {
+    Io(io::Error),
+    Parse(usize, E),
+}
+
+impl<E> From<io::Error> for ReadError<E> {
+    #[inline]
+    fn from(error: io::Error) -> Self {
+        ReadError::Io(error)
+    }
+}
+
+#[derive(Debug)]
+pub struct Reader<R> {
+    reader: R,
+    buffer: Vec<u8>,
+    start: usize,
+    offset: usize,
+    chunk: usize,
+    eof: bool,
+}
+
+impl<R> Reader<R>
+where
+    R: Read,
+{
+    #[inline]
+    pub fn new(reader: R) -> Self {
+        Reader::with_capacity(8 * 1024, reader)
+    }
+
+    #[inline]
+    pub fn with_capacity(capacity: usize, reader: R) -> Self {
+        Reader {
+            reader,
+            buffer: Vec::with_capacity(capacity),
+            start: 0,
+            offset: 0,
+            chunk: capacity.max(1),
+            eof: false,
+        }
+    }
+
+    #[inline]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    #[inline]
+    pub fn buffer(&self) -> &[u8] {
+        &self.buffer[self.start..]
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    // Parses the next item, reading more whenever the parser reports `Incomplete`. Returns `None`
+    // once the reader is exhausted and every byte has been consumed. Nothing is lost on an I/O
+    // error (e.g. `WouldBlock`), so parsing can simply be retried. Outputs can't borrow from the
+    // buffer, which is refilled in place.
+    pub fn parse<P, O, E>(&mut self, parser: &mut P) -> std::result::Result<Option<O>, ReadError<E>>
+    where
+        P: for<'b> Parser<Partial<&'b [u8]>, Output = O, Error = E>,
+        E: Incomplete,
+    {
+        loop {
+            if self.start == self.buffer.len() {
+                if self.eof {
+                    return Ok(None);
+                }
+                self.fill(Needed::Unknown)?;
+                continue;
+            }
+
+            let input = Partial {
+                input: &self.buffer[self.start..],
+                complete: self.eof,
+            };
+            match parser.parse(input, 0) {
+                Ok((to, output)) => {
+                    self.start += to;
+                    self.offset += to;
+                    return Ok(Some(output));
+                }
+                Err((from, error)) => match error.needed() {
+                    Some(needed) if !self.eof => self.fill(needed)?,
+                    _ => return Err(ReadError::Parse(self.offset + from, error)),
+                },
+            }
+        }
+    }
+
+    fn fill(&mut self, needed: Needed) -> io::Result<()> {
+        self.buffer.drain(..self.start);
+        self.start = 0;
+
+        let len = self.buffer.len();
+        let size = match needed {
+            Needed::Size(size) => size.max(self.chunk),
+            Needed::Unknown => self.chunk,
+        };
+        self.buffer.resize(len + size, 0);
+        let read = loop {
+            match self.reader.read(&mut self.buffer[len..]) {
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+                otherwise => break otherwise,
+            }
+        };
+        self.buffer.truncate(len + *read.as_ref().unwrap_or(&0));
+        if read? == 0 {
+            self.eof = true;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+use crate::error::{Ascii, Error};
+use crate::partial::Partial;
+use crate::{input, Parser, Result};
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Satisfy<F>(pub F)
+where
+    F: FnMut(u8) -> bool;
+
+impl<'a, F, Input> Parser<Partial<&'a Input>> for Satisfy<F>
+where
+    F: FnMut(u8) -> bool,
+    Input: AsRef<[u8]> + ?Sized,
+{
+    type Output = u8;
+    type Error = Error<'static>;
+
+    #[inline(always)]
+    fn parse(
+        &mut self,
+        input: Partial<&'a Input>,
+        from: usize,
+    ) -> Result<Self::Output, Self::Error> {
+        let f = &mut self.0;
+        let input = Partial {
+            input: input.input.as_ref(),
+            complete: input.complete,
+        };
+        match input::Satisfy(|u8| u8 <= 0x7F && f(u8)).parse(input, from) {
+            Err((from, Error::Satisfy)) => Err((from, Error::Ascii(Ascii::Satisfy))),
+            otherwise => otherwise,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TakeWhile<F>(pub F)
+where
+    F: FnMut(u8) -> bool;
+
+impl<'a, F, Input> Parser<Partial<&'a Input>> for TakeWhile<F>
+where
+    F: FnMut(u8) -> bool,
+    Input: AsRef<[u8]> + ?Sized,
+{
+    type Output = &'a str;
+    type Error = Error<'static>;
+
+    #[inline(always)]
+    fn parse(
+        &mut self,
+        input: Partial<&'a Input>,
+        from: usize,
+    ) -> Result<Self::Output, Self::Error> {
+        let f = &mut self.0;
+        let input = Partial {
+            input: input.input.as_ref(),
+            complete: input.complete,
+        };
+        let (to, slice) = input::TakeWhile(|u8| u8 <= 0x7F && f(u8)).parse(input, from)?;
+        Ok((to, std::str::from_utf8(slice).unwrap()))
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TakeWhile1<F>(pub F)
+where
+    F: FnMut(u8) -> bool;
+
+impl<'a, F, Input> Parser<Partial<&'a Input>> for TakeWhile1<F>
+where
+    F: FnMut(u8) -> bool,
+    Input: AsRef<[u8]> + ?Sized,
+{
+    type Output = &'a str;
+    type Error = Error<'static>;
+
+    #[inline(always)]
+    fn parse(
+        &mut self,
+        input: Partial<&'a Input>,
+        from: usize,
+    ) -> Result<Self::Output, Self::Error> {
+        match TakeWhile(&mut self.0).parse(input, from) {
+            Ok((_, "")) => Err((from, Error::Ascii(Ascii::TakeWhile1))),
+            otherwise => otherwise,
+        }
+    }
+}
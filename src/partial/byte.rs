@@ -0,0 +1,222 @@
+use crate::byte;
+use crate::error::Error;
+use crate::input::{self, incomplete};
+use crate::partial::Partial;
+use crate::{Parser, Result};
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Byte(pub u8);
+
+impl<'a> Parser<Partial<&'a [u8]>> for Byte {
+    type Output = u8;
+    type Error = Error<'static>;
+
+    #[inline(always)]
+    fn parse(
+        &mut self,
+        input: Partial<&'a [u8]>,
+        from: usize,
+    ) -> Result<Self::Output, Self::Error> {
+        if from >= input.input.len() && !input.complete {
+            return incomplete(input.input.len(), Some(from + 1));
+        }
+        Parser::parse(&mut self.0, input.input, from)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Bytes<'tmp>(pub &'tmp [u8]);
+
+impl<'a, 'tmp> Parser<Partial<&'a [u8]>> for Bytes<'tmp> {
+    type Output = &'a [u8];
+    type Error = Error<'tmp>;
+
+    #[inline(always)]
+    fn parse(
+        &mut self,
+        input: Partial<&'a [u8]>,
+        from: usize,
+    ) -> Result<Self::Output, Self::Error> {
+        let rest = input.input.get(from..).unwrap_or_default();
+        if rest.len() < self.0.len() && self.0.starts_with(rest) && !input.complete {
+            return incomplete(input.input.len(), Some(from + self.0.len()));
+        }
+        Parser::parse(&mut self.0, input.input, from)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Satisfy<F>(pub F)
+where
+    F: FnMut(u8) -> bool;
+
+impl<'a, F> Parser<Partial<&'a [u8]>> for Satisfy<F>
+where
+    F: FnMut(u8) -> bool,
+{
+    type Output = u8;
+    type Error = Error<'static>;
+
+    #[inline(always)]
+    fn parse(
+        &mut self,
+        input: Partial<&'a [u8]>,
+        from: usize,
+    ) -> Result<Self::Output, Self::Error> {
+        input::Satisfy(&mut self.0).parse(input, from)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TakeWhile<F>(pub F)
+where
+    F: FnMut(u8) -> bool;
+
+impl<'a, F> Parser<Partial<&'a [u8]>> for TakeWhile<F>
+where
+    F: FnMut(u8) -> bool,
+{
+    type Output = &'a [u8];
+    type Error = Error<'static>;
+
+    #[inline(always)]
+    fn parse(
+        &mut self,
+        input: Partial<&'a [u8]>,
+        from: usize,
+    ) -> Result<Self::Output, Self::Error> {
+        input::TakeWhile(&mut self.0).parse(input, from)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TakeWhile1<F>(pub F)
+where
+    F: FnMut(u8) -> bool;
+
+impl<'a, F> Parser<Partial<&'a [u8]>> for TakeWhile1<F>
+where
+    F: FnMut(u8) -> bool,
+{
+    type Output = &'a [u8];
+    type Error = Error<'static>;
+
+    #[inline(always)]
+    fn parse(
+        &mut self,
+        input: Partial<&'a [u8]>,
+        from: usize,
+    ) -> Result<Self::Output, Self::Error> {
+        input::TakeWhile1(&mut self.0).parse(input, from)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Capture<P>(pub P);
+
+impl<'a, P> Parser<Partial<&'a [u8]>> for Capture<P>
+where
+    P: Parser<Partial<&'a [u8]>>,
+{
+    type Output = &'a [u8];
+    type Error = P::Error;
+
+    #[inline(always)]
+    fn parse(
+        &mut self,
+        input: Partial<&'a [u8]>,
+        from: usize,
+    ) -> Result<Self::Output, Self::Error> {
+        input::Capture(self.0.by_ref()).parse(input, from)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Any;
+
+impl<'a> Parser<Partial<&'a [u8]>> for Any {
+    type Output = u8;
+    type Error = Error<'static>;
+
+    #[inline(always)]
+    fn parse(
+        &mut self,
+        input: Partial<&'a [u8]>,
+        from: usize,
+    ) -> Result<Self::Output, Self::Error> {
+        input::Any.parse(input, from)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Peek;
+
+impl<'a> Parser<Partial<&'a [u8]>> for Peek {
+    type Output = u8;
+    type Error = Error<'static>;
+
+    #[inline(always)]
+    fn parse(
+        &mut self,
+        input: Partial<&'a [u8]>,
+        from: usize,
+    ) -> Result<Self::Output, Self::Error> {
+        input::Peek.parse(input, from)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Take(pub usize);
+
+impl<'a> Parser<Partial<&'a [u8]>> for Take {
+    type Output = &'a [u8];
+    type Error = Error<'static>;
+
+    #[inline(always)]
+    fn parse(
+        &mut self,
+        input: Partial<&'a [u8]>,
+        from: usize,
+    ) -> Result<Self::Output, Self::Error> {
+        input::Take(self.0).parse(input, from)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct End;
+
+impl<'a> Parser<Partial<&'a [u8]>> for End {
+    type Output = ();
+    type Error = Error<'static>;
+
+    #[inline(always)]
+    fn parse(
+        &mut self,
+        input: Partial<&'a [u8]>,
+        from: usize,
+    ) -> Result<Self::Output, Self::Error> {
+        input::End.parse(input, from)
+    }
+}
+
+macro_rules! read {
+    ($endianness:ident, $($ty:ident)*) => {
+        pub enum $endianness {}
+
+        impl $endianness {
+            $(
+                #[inline(always)]
+                pub fn $ty(input: Partial<&[u8]>, from: usize) -> Result<$ty, Error<'static>> {
+                    let to = from + ::std::mem::size_of::<$ty>();
+                    if to > input.input.len() && !input.complete {
+                        return incomplete(input.input.len(), Some(to));
+                    }
+                    byte::$endianness::$ty(input.input, from)
+                }
+            )*
+        }
+    };
+}
+
+read!(LittleEndian, u8 u16 u32 u64 i8 i16 i32 i64 f32 f64);
+read!(BigEndian, u8 u16 u32 u64 i8 i16 i32 i64 f32 f64);
@@ -0,0 +1,149 @@
+use crate::error::Error;
+use crate::input::{self, incomplete};
+use crate::partial::Partial;
+use crate::{Parser, Result};
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Char(pub char);
+
+impl<'a> Parser<Partial<&'a str>> for Char {
+    type Output = char;
+    type Error = Error<'static>;
+
+    #[inline(always)]
+    fn parse(&mut self, input: Partial<&'a str>, from: usize) -> Result<Self::Output, Self::Error> {
+        let rest = input.input.as_bytes().get(from..).unwrap_or_default();
+        let len = self.0.len_utf8();
+        let matches = self.0.encode_utf8(&mut [0; 4]).as_bytes().starts_with(rest);
+        if rest.len() < len && matches && !input.complete {
+            return incomplete(input.input.len(), Some(from + len));
+        }
+        Parser::parse(&mut self.0, input.input, from)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Str<'tmp>(pub &'tmp str);
+
+impl<'a, 'tmp> Parser<Partial<&'a str>> for Str<'tmp> {
+    type Output = &'a str;
+    type Error = Error<'tmp>;
+
+    #[inline(always)]
+    fn parse(&mut self, input: Partial<&'a str>, from: usize) -> Result<Self::Output, Self::Error> {
+        let rest = input.input.as_bytes().get(from..).unwrap_or_default();
+        if rest.len() < self.0.len() && self.0.as_bytes().starts_with(rest) && !input.complete {
+            return incomplete(input.input.len(), Some(from + self.0.len()));
+        }
+        Parser::parse(&mut self.0, input.input, from)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Satisfy<F>(pub F)
+where
+    F: FnMut(char) -> bool;
+
+impl<'a, F> Parser<Partial<&'a str>> for Satisfy<F>
+where
+    F: FnMut(char) -> bool,
+{
+    type Output = char;
+    type Error = Error<'static>;
+
+    #[inline(always)]
+    fn parse(&mut self, input: Partial<&'a str>, from: usize) -> Result<Self::Output, Self::Error> {
+        input::Satisfy(&mut self.0).parse(input, from)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TakeWhile<F>(pub F)
+where
+    F: FnMut(char) -> bool;
+
+impl<'a, F> Parser<Partial<&'a str>> for TakeWhile<F>
+where
+    F: FnMut(char) -> bool,
+{
+    type Output = &'a str;
+    type Error = Error<'static>;
+
+    #[inline(always)]
+    fn parse(&mut self, input: Partial<&'a str>, from: usize) -> Result<Self::Output, Self::Error> {
+        input::TakeWhile(&mut self.0).parse(input, from)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TakeWhile1<F>(pub F)
+where
+    F: FnMut(char) -> bool;
+
+impl<'a, F> Parser<Partial<&'a str>> for TakeWhile1<F>
+where
+    F: FnMut(char) -> bool,
+{
+    type Output = &'a str;
+    type Error = Error<'static>;
+
+    #[inline(always)]
+    fn parse(&mut self, input: Partial<&'a str>, from: usize) -> Result<Self::Output, Self::Error> {
+        input::TakeWhile1(&mut self.0).parse(input, from)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Capture<P>(pub P);
+
+impl<'a, P> Parser<Partial<&'a str>> for Capture<P>
+where
+    P: Parser<Partial<&'a str>>,
+{
+    type Output = &'a str;
+    type Error = P::Error;
+
+    #[inline(always)]
+    fn parse(&mut self, input: Partial<&'a str>, from: usize) -> Result<Self::Output, Self::Error> {
+        input::Capture(self.0.by_ref()).parse(input, from)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Any;
+
+impl<'a> Parser<Partial<&'a str>> for Any {
+    type Output = char;
+    type Error = Error<'static>;
+
+    #[inline(always)]
+    fn parse(&mut self, input: Partial<&'a str>, from: usize) -> Result<Self::Output, Self::Error> {
+        input::Any.parse(input, from)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Peek;
+
+impl<'a> Parser<Partial<&'a str>> for Peek {
+    type Output = char;
+    type Error = Error<'static>;
+
+    #[inline(always)]
+    fn parse(&mut self, input: Partial<&'a str>, from: usize) -> Result<Self::Output, Self::Error> {
+        input::Peek.parse(input, from)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct End;
+
+impl<'a> Parser<Partial<&'a str>> for End {
+    type Output = ();
+    type Error = Error<'static>;
+
+    #[inline(always)]
+    fn parse(&mut self, input: Partial<&'a str>, from: usize) -> Result<Self::Output, Self::Error> {
+        input::End.parse(input, from)
+    }
+}
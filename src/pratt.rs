@@ -0,0 +1,397 @@
+use crate::error::Merge;
+use crate::{Parser, Result};
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Assoc {
+    Left,
+    Right,
+}
+
+#[derive(Clone, Debug)]
+pub struct Pratt<A, Pre, In, Post> {
+    atom: A,
+    prefix: Pre,
+    infix: In,
+    postfix: Post,
+}
+
+impl<A> Pratt<A, (), (), ()> {
+    #[inline]
+    pub fn new(atom: A) -> Self {
+        Pratt {
+            atom,
+            prefix: (),
+            infix: (),
+            postfix: (),
+        }
+    }
+}
+
+// A precedence of `p` maps to the binding powers `2p` and `2p + 1`, so that operators of equal
+// precedence bind according to their associativity.
+#[allow(clippy::type_complexity)]
+impl<A, Pre, In, Post> Pratt<A, Pre, In, Post> {
+    #[inline]
+    pub fn prefix<B, F, Input>(
+        self,
+        op: B,
+        precedence: u32,
+        f: F,
+    ) -> Pratt<A, (Pre, Prefix<B, F, B::Output>), In, Post>
+    where
+        A: Parser<Input>,
+        B: Parser<Input, Error = A::Error>,
+        F: FnMut(B::Output, A::Output) -> A::Output,
+    {
+        Pratt {
+            atom: self.atom,
+            prefix: (self.prefix, Prefix(Op::new(op, 0, 2 * precedence + 1, f))),
+            infix: self.infix,
+            postfix: self.postfix,
+        }
+    }
+
+    #[inline]
+    pub fn infix<B, F, Input>(
+        self,
+        op: B,
+        assoc: Assoc,
+        precedence: u32,
+        f: F,
+    ) -> Pratt<A, Pre, (In, Infix<B, F, B::Output>), Post>
+    where
+        A: Parser<Input>,
+        B: Parser<Input, Error = A::Error>,
+        F: FnMut(A::Output, B::Output, A::Output) -> A::Output,
+    {
+        let (left, right) = match assoc {
+            Assoc::Left => (2 * precedence, 2 * precedence + 1),
+            Assoc::Right => (2 * precedence + 1, 2 * precedence),
+        };
+        Pratt {
+            atom: self.atom,
+            prefix: self.prefix,
+            infix: (self.infix, Infix(Op::new(op, left, right, f))),
+            postfix: self.postfix,
+        }
+    }
+
+    #[inline]
+    pub fn postfix<B, F, Input>(
+        self,
+        op: B,
+        precedence: u32,
+        f: F,
+    ) -> Pratt<A, Pre, In, (Post, Postfix<B, F, B::Output>)>
+    where
+        A: Parser<Input>,
+        B: Parser<Input, Error = A::Error>,
+        F: FnMut(A::Output, B::Output) -> A::Output,
+    {
+        Pratt {
+            atom: self.atom,
+            prefix: self.prefix,
+            infix: self.infix,
+            postfix: (self.postfix, Postfix(Op::new(op, 2 * precedence, 0, f))),
+        }
+    }
+}
+
+impl<A, Pre, In, Post> Pratt<A, Pre, In, Post> {
+    fn expr<Input>(&mut self, input: Input, from: usize, min: u32) -> Result<A::Output, A::Error>
+    where
+        A: Parser<Input>,
+        A::Error: Merge + Default,
+        Pre: Operators<Input, A::Output, A::Error>,
+        In: Operators<Input, A::Output, A::Error>,
+        Post: Operators<Input, A::Output, A::Error>,
+        Input: Copy,
+    {
+        let (mut from, mut lhs) = match self.prefix.parse(input, from) {
+            Ok((to, (index, _, right))) => {
+                let (to, rhs) = self.expr(input, to, right)?;
+                (to, self.prefix.apply(index, None, Some(rhs)))
+            }
+            Err((from2, error)) if from == from2 => match self.atom.parse(input, from) {
+                Err((from2, error2)) if from == from2 => return Err((from, error.merge(error2))),
+                otherwise => otherwise?,
+            },
+            Err((from2, error)) => return Err((from2, error)),
+        };
+
+        loop {
+            match self.postfix.parse(input, from) {
+                Ok((_, (index, left, _))) if left < min => {
+                    self.postfix.discard(index);
+                    break;
+                }
+                Ok((to, (index, _, _))) => {
+                    from = to;
+                    lhs = self.postfix.apply(index, Some(lhs), None);
+                    continue;
+                }
+                Err((from2, _)) if from == from2 => {}
+                Err((from2, error)) => return Err((from2, error)),
+            }
+
+            match self.infix.parse(input, from) {
+                Ok((_, (index, left, _))) if left < min => {
+                    self.infix.discard(index);
+                    break;
+                }
+                Ok((to, (index, _, right))) => {
+                    let (to, rhs) = self.expr(input, to, right)?;
+                    from = to;
+                    lhs = self.infix.apply(index, Some(lhs), Some(rhs));
+                }
+                Err((from2, _)) if from == from2 => break,
+                Err((from2, error)) => return Err((from2, error)),
+            }
+        }
+
+        Ok((from, lhs))
+    }
+}
+
+impl<A, Pre, In, Post, Input> Parser<Input> for Pratt<A, Pre, In, Post>
+where
+    A: Parser<Input>,
+    A::Error: Merge + Default,
+    Pre: Operators<Input, A::Output, A::Error>,
+    In: Operators<Input, A::Output, A::Error>,
+    Post: Operators<Input, A::Output, A::Error>,
+    Input: Copy,
+{
+    type Output = A::Output;
+    type Error = A::Error;
+
+    #[inline]
+    fn parse(&mut self, input: Input, from: usize) -> Result<Self::Output, Self::Error> {
+        let result = self.expr(input, from, 0);
+        if result.is_err() {
+            // Operators whose operands failed never got to pop their outputs.
+            self.prefix.clear();
+            self.infix.clear();
+            self.postfix.clear();
+        }
+        result
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Op<B, F, O> {
+    parser: B,
+    left: u32,
+    right: u32,
+    f: F,
+    // Operator outputs waiting for their operands; a stack because operators nest.
+    stack: Vec<O>,
+}
+
+impl<B, F, O> Op<B, F, O> {
+    #[inline]
+    fn new(parser: B, left: u32, right: u32, f: F) -> Self {
+        Op {
+            parser,
+            left,
+            right,
+            f,
+            stack: Vec::new(),
+        }
+    }
+
+    #[inline]
+    fn parse<Input>(&mut self, input: Input, from: usize) -> Result<(u32, u32), B::Error>
+    where
+        B: Parser<Input, Output = O>,
+    {
+        let (to, output) = self.parser.parse(input, from)?;
+        self.stack.push(output);
+        Ok((to, (self.left, self.right)))
+    }
+
+    #[inline]
+    fn pop(&mut self) -> O {
+        self.stack.pop().expect("operator output")
+    }
+
+    #[inline]
+    fn clear(&mut self) {
+        self.stack.clear();
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Prefix<B, F, O>(Op<B, F, O>);
+
+#[derive(Clone, Debug)]
+pub struct Infix<B, F, O>(Op<B, F, O>);
+
+#[derive(Clone, Debug)]
+pub struct Postfix<B, F, O>(Op<B, F, O>);
+
+pub trait Operator<Input, X, E> {
+    fn parse(&mut self, input: Input, from: usize) -> Result<(u32, u32), E>;
+    fn discard(&mut self);
+    fn clear(&mut self);
+    fn apply(&mut self, lhs: Option<X>, rhs: Option<X>) -> X;
+}
+
+impl<B, F, X, Input> Operator<Input, X, B::Error> for Prefix<B, F, B::Output>
+where
+    B: Parser<Input>,
+    F: FnMut(B::Output, X) -> X,
+{
+    #[inline]
+    fn parse(&mut self, input: Input, from: usize) -> Result<(u32, u32), B::Error> {
+        self.0.parse(input, from)
+    }
+
+    #[inline]
+    fn discard(&mut self) {
+        self.0.pop();
+    }
+
+    #[inline]
+    fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    #[inline]
+    fn apply(&mut self, _: Option<X>, rhs: Option<X>) -> X {
+        let op = self.0.pop();
+        (self.0.f)(op, rhs.unwrap())
+    }
+}
+
+impl<B, F, X, Input> Operator<Input, X, B::Error> for Infix<B, F, B::Output>
+where
+    B: Parser<Input>,
+    F: FnMut(X, B::Output, X) -> X,
+{
+    #[inline]
+    fn parse(&mut self, input: Input, from: usize) -> Result<(u32, u32), B::Error> {
+        self.0.parse(input, from)
+    }
+
+    #[inline]
+    fn discard(&mut self) {
+        self.0.pop();
+    }
+
+    #[inline]
+    fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    #[inline]
+    fn apply(&mut self, lhs: Option<X>, rhs: Option<X>) -> X {
+        let op = self.0.pop();
+        (self.0.f)(lhs.unwrap(), op, rhs.unwrap())
+    }
+}
+
+impl<B, F, X, Input> Operator<Input, X, B::Error> for Postfix<B, F, B::Output>
+where
+    B: Parser<Input>,
+    F: FnMut(X, B::Output) -> X,
+{
+    #[inline]
+    fn parse(&mut self, input: Input, from: usize) -> Result<(u32, u32), B::Error> {
+        self.0.parse(input, from)
+    }
+
+    #[inline]
+    fn discard(&mut self) {
+        self.0.pop();
+    }
+
+    #[inline]
+    fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    #[inline]
+    fn apply(&mut self, lhs: Option<X>, _: Option<X>) -> X {
+        let op = self.0.pop();
+        (self.0.f)(lhs.unwrap(), op)
+    }
+}
+
+pub trait Operators<Input, X, E> {
+    const LEN: usize;
+
+    fn parse(&mut self, input: Input, from: usize) -> Result<(usize, u32, u32), E>;
+    fn discard(&mut self, index: usize);
+    fn clear(&mut self);
+    fn apply(&mut self, index: usize, lhs: Option<X>, rhs: Option<X>) -> X;
+}
+
+impl<Input, X, E> Operators<Input, X, E> for ()
+where
+    E: Default,
+{
+    const LEN: usize = 0;
+
+    #[inline]
+    fn parse(&mut self, _input: Input, from: usize) -> Result<(usize, u32, u32), E> {
+        Err((from, E::default()))
+    }
+
+    fn discard(&mut self, _index: usize) {
+        unreachable!()
+    }
+
+    #[inline]
+    fn clear(&mut self) {}
+
+    fn apply(&mut self, _index: usize, _lhs: Option<X>, _rhs: Option<X>) -> X {
+        unreachable!()
+    }
+}
+
+impl<Rest, O, Input, X, E> Operators<Input, X, E> for (Rest, O)
+where
+    Rest: Operators<Input, X, E>,
+    O: Operator<Input, X, E>,
+    E: Merge,
+    Input: Copy,
+{
+    const LEN: usize = Rest::LEN + 1;
+
+    #[inline]
+    fn parse(&mut self, input: Input, from: usize) -> Result<(usize, u32, u32), E> {
+        match self.0.parse(input, from) {
+            Err((from2, error)) if from == from2 => match self.1.parse(input, from) {
+                Ok((to, (left, right))) => Ok((to, (Rest::LEN, left, right))),
+                Err((from2, error2)) if from == from2 => Err((from, error.merge(error2))),
+                Err((from2, error2)) => Err((from2, error2)),
+            },
+            otherwise => otherwise,
+        }
+    }
+
+    #[inline]
+    fn discard(&mut self, index: usize) {
+        if index == Rest::LEN {
+            self.1.discard()
+        } else {
+            self.0.discard(index)
+        }
+    }
+
+    #[inline]
+    fn clear(&mut self) {
+        self.0.clear();
+        self.1.clear();
+    }
+
+    #[inline]
+    fn apply(&mut self, index: usize, lhs: Option<X>, rhs: Option<X>) -> X {
+        if index == Rest::LEN {
+            self.1.apply(lhs, rhs)
+        } else {
+            self.0.apply(index, lhs, rhs)
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use std::cell::RefCell;
+
+use crate::{Parser, Result};
+
+#[derive(Debug, Default)]
+pub struct Errors<E>(RefCell<Vec<(usize, E)>>);
+
+impl<E> Errors<E> {
+    #[inline]
+    pub fn new() -> Self {
+        Errors(RefCell::new(Vec::new()))
+    }
+
+    #[inline]
+    pub fn push(&self, from: usize, error: E) {
+        self.0.borrow_mut().push((from, error));
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.borrow().len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.borrow().is_empty()
+    }
+
+    #[inline]
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint(self.len())
+    }
+
+    // Drop every error recorded since `checkpoint`.
+    #[inline]
+    pub fn rewind(&self, checkpoint: Checkpoint) {
+        self.0.borrow_mut().truncate(checkpoint.0);
+    }
+
+    #[inline]
+    pub fn take(&self) -> Vec<(usize, E)> {
+        std::mem::take(&mut *self.0.borrow_mut())
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> Vec<(usize, E)> {
+        self.0.into_inner()
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Checkpoint(usize);
+
+// `Recover` and `Missing` record errors as soon as they recover, and combinators like `Or`, `Try`
+// and `Optional` don't know about the collector, so a branch that recovers and then fails would
+// leave its errors behind. Wrap such speculative branches in `Rewind` to drop them again.
+#[derive(Copy, Clone, Debug)]
+pub struct Rewind<'e, A, E>(pub(crate) A, pub(crate) &'e Errors<E>);
+
+impl<'e, A, E, Input> Parser<Input> for Rewind<'e, A, E>
+where
+    A: Parser<Input>,
+{
+    type Output = A::Output;
+    type Error = A::Error;
+
+    #[inline(always)]
+    fn parse(&mut self, input: Input, from: usize) -> Result<Self::Output, Self::Error> {
+        let checkpoint = self.1.checkpoint();
+        match self.0.parse(input, from) {
+            Ok(ok) => Ok(ok),
+            Err(err) => {
+                self.1.rewind(checkpoint);
+                Err(err)
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct Recover<'e, A, B, F, E>(
+    pub(crate) A,
+    pub(crate) &'e Errors<E>,
+    pub(crate) B,
+    pub(crate) F,
+);
+
+impl<'e, A, B, F, E, Input> Parser<Input> for Recover<'e, A, B, F, E>
+where
+    A: Parser<Input, Error = E>,
+    B: Parser<Input>,
+    F: FnMut(B::Output) -> A::Output,
+    Input: Copy,
+{
+    type Output = A::Output;
+    type Error = A::Error;
+
+    #[inline(always)]
+    fn parse(&mut self, input: Input, from: usize) -> Result<Self::Output, Self::Error> {
+        match self.0.parse(input, from) {
+            Ok((from, output)) => Ok((from, output)),
+            Err((from2, error)) => match self.2.parse(input, from2) {
+                // Recovering without making any progress would turn every repetition into an
+                // infinite loop, so report the original failure instead.
+                Ok((to, _)) if to == from => Err((from2, error)),
+                Ok((to, output)) => {
+                    self.1.push(from2, error);
+                    Ok((to, self.3(output)))
+                }
+                Err(_) => Err((from2, error)),
+            },
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct Missing<'e, A, F, E>(pub(crate) A, pub(crate) &'e Errors<E>, pub(crate) F);
+
+impl<'e, A, F, E, Input> Parser<Input> for Missing<'e, A, F, E>
+where
+    A: Parser<Input, Error = E>,
+    F: FnMut() -> A::Output,
+{
+    type Output = A::Output;
+    type Error = A::Error;
+
+    #[inline(always)]
+    fn parse(&mut self, input: Input, from: usize) -> Result<Self::Output, Self::Error> {
+        match self.0.parse(input, from) {
+            Ok((from, output)) => Ok((from, output)),
+            Err((from2, error)) if from == from2 => {
+                self.1.push(from, error);
+                Ok((from, self.2()))
+            }
+            Err((from2, error)) => Err((from2, error)),
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use std::fmt;
+
+use crate::location::LineIndex;
+
+const RED: &str = "\x1b[1;31m";
+const BLUE: &str = "\x1b[1;34m";
+const BOLD: &str = "\x1b[1m";
+const RESET: &str = "\x1b[0m";
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Report<'a, E> {
+    input: &'a [u8],
+    index: Option<&'a LineIndex<'a>>,
+    offset: usize,
+    error: &'a E,
+    color: bool,
+}
+
+impl<'a, E> Report<'a, E> {
+    pub fn new<Input>(input: &'a Input, offset: usize, error: &'a E) -> Self
+    where
+        Input: AsRef<[u8]> + ?Sized,
+    {
+        Report {
+            input: input.as_ref(),
+            index: None,
+            offset,
+            error,
+            color: false,
+        }
+    }
+
+    // Reuse an existing index when rendering many reports against the same input.
+    pub fn with_index(index: &'a LineIndex<'a>, offset: usize, error: &'a E) -> Self {
+        Report {
+            input: &[],
+            index: Some(index),
+            offset,
+            error,
+            color: false,
+        }
+    }
+
+    pub fn color(self, color: bool) -> Self {
+        Report { color, ..self }
+    }
+}
+
+impl<'a, E> fmt::Display for Report<'a, E>
+where
+    E: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (red, blue, bold, reset) = if self.color {
+            (RED, BLUE, BOLD, RESET)
+        } else {
+            ("", "", "", "")
+        };
+
+        let owned;
+        let index = match self.index {
+            Some(index) => index,
+            None => {
+                owned = LineIndex::new(self.input);
+                &owned
+            }
+        };
+        let location = index.location(self.offset);
+        let bytes = index.line(location.line).unwrap_or_default();
+        let start = index.line_start(location.line).unwrap_or_default();
+        let line = String::from_utf8_lossy(bytes);
+        let number = (location.line + 1).to_string();
+        let gutter = " ".repeat(number.len());
+        // Pad with the line's own bytes up to the offset, decoded the same way as the line, so
+        // the caret lines up even with invalid UTF-8. Tabs are kept for the same reason.
+        let prefix = &bytes[..(location.offset - start).min(bytes.len())];
+        let padding = String::from_utf8_lossy(prefix)
+            .chars()
+            .map(|char| if char == '\t' { '\t' } else { ' ' })
+            .collect::<String>();
+
+        writeln!(f, "{}error{}{}: {}{}", red, reset, bold, self.error, reset)?;
+        writeln!(f, "{}{}-->{} {}", gutter, blue, reset, location)?;
+        writeln!(f, "{}{} |{}", gutter, blue, reset)?;
+        writeln!(f, "{}{} |{} {}", blue, number, reset, line)?;
+        write!(
+            f,
+            "{}{} |{} {}{}^{}",
+            gutter, blue, reset, padding, red, reset
+        )
+    }
+}
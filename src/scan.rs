@@ -0,0 +1,56 @@
+// Word-at-a-time searches, eight bytes per step. A byte of `word` equals `byte` iff the same byte
+// of `word ^ splat(byte)` is zero, and `has_zero` detects zero bytes without branching on each.
+
+const LO: u64 = 0x0101_0101_0101_0101;
+const HI: u64 = 0x8080_8080_8080_8080;
+
+#[inline(always)]
+fn splat(byte: u8) -> u64 {
+    LO * byte as u64
+}
+
+#[inline(always)]
+fn has_zero(word: u64) -> bool {
+    word.wrapping_sub(LO) & !word & HI != 0
+}
+
+#[inline]
+pub fn find_byte(haystack: &[u8], needle: u8) -> Option<usize> {
+    find_any(haystack, &[needle])
+}
+
+#[inline]
+pub fn find_any(haystack: &[u8], needles: &[u8]) -> Option<usize> {
+    let mut chunks = haystack.chunks_exact(8);
+    let mut offset = 0;
+    for chunk in &mut chunks {
+        let word = u64::from_ne_bytes([
+            chunk[0], chunk[1], chunk[2], chunk[3], chunk[4], chunk[5], chunk[6], chunk[7],
+        ]);
+        if needles.iter().any(|&needle| has_zero(word ^ splat(needle))) {
+            break;
+        }
+        offset += 8;
+    }
+    haystack[offset..]
+        .iter()
+        .position(|byte| needles.contains(byte))
+        .map(|index| offset + index)
+}
+
+#[inline]
+pub fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    let (&first, rest) = match needle.split_first() {
+        Some(split) => split,
+        None => return Some(0),
+    };
+    let mut offset = 0;
+    while let Some(index) = find_byte(&haystack[offset..], first) {
+        let at = offset + index;
+        if haystack[at + 1..].starts_with(rest) {
+            return Some(at);
+        }
+        offset = at + 1;
+    }
+    None
+}
@@ -0,0 +1,151 @@
+use std::ops::Index;
+
+use crate::{Parser, Result};
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    #[inline]
+    pub fn new(start: usize, end: usize) -> Self {
+        Span { start, end }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    // The smallest span covering both.
+    #[inline]
+    pub fn merge(self, other: Span) -> Span {
+        Span::new(self.start.min(other.start), self.end.max(other.end))
+    }
+
+    #[inline]
+    pub fn slice<'a, I>(&self, input: &'a I) -> &'a I::Output
+    where
+        I: Index<std::ops::Range<usize>> + ?Sized,
+    {
+        &input[self.start..self.end]
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Spanned<T> {
+    pub span: Span,
+    pub value: T,
+}
+
+impl<T> Spanned<T> {
+    #[inline]
+    pub fn new(span: Span, value: T) -> Self {
+        Spanned { span, value }
+    }
+
+    #[inline]
+    pub fn map<U, F>(self, f: F) -> Spanned<U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        Spanned::new(self.span, f(self.value))
+    }
+
+    #[inline]
+    pub fn as_ref(&self) -> Spanned<&T> {
+        Spanned::new(self.span, &self.value)
+    }
+
+    // Combines two values into one spanning both.
+    #[inline]
+    pub fn merge<U, V, F>(self, other: Spanned<U>, f: F) -> Spanned<V>
+    where
+        F: FnOnce(T, U) -> V,
+    {
+        Spanned::new(self.span.merge(other.span), f(self.value, other.value))
+    }
+}
+
+pub trait HasSpan {
+    fn span(&self) -> Span;
+}
+
+impl HasSpan for Span {
+    #[inline]
+    fn span(&self) -> Span {
+        *self
+    }
+}
+
+impl<T> HasSpan for (Span, T) {
+    #[inline]
+    fn span(&self) -> Span {
+        self.0
+    }
+}
+
+impl<T, U> HasSpan for (Span, T, U) {
+    #[inline]
+    fn span(&self) -> Span {
+        self.0
+    }
+}
+
+impl<T> HasSpan for Spanned<T> {
+    #[inline]
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl<T> HasSpan for &T
+where
+    T: HasSpan + ?Sized,
+{
+    #[inline]
+    fn span(&self) -> Span {
+        (**self).span()
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct WithSpan<A>(pub(crate) A);
+
+impl<A, Input> Parser<Input> for WithSpan<A>
+where
+    A: Parser<Input>,
+{
+    type Output = (Span, A::Output);
+    type Error = A::Error;
+
+    #[inline(always)]
+    fn parse(&mut self, input: Input, from: usize) -> Result<Self::Output, Self::Error> {
+        let (to, output) = self.0.parse(input, from)?;
+        Ok((to, (Span::new(from, to), output)))
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct IntoSpanned<A>(pub(crate) A);
+
+impl<A, Input> Parser<Input> for IntoSpanned<A>
+where
+    A: Parser<Input>,
+{
+    type Output = Spanned<A::Output>;
+    type Error = A::Error;
+
+    #[inline(always)]
+    fn parse(&mut self, input: Input, from: usize) -> Result<Self::Output, Self::Error> {
+        let (to, output) = self.0.parse(input, from)?;
+        Ok((to, Spanned::new(Span::new(from, to), output)))
+    }
+}
@@ -1,5 +1,6 @@
+use crate::class::Class;
 use crate::error::Error;
-use crate::{Parser, Result};
+use crate::{input, scan, Parser, Result};
 
 impl<'a> Parser<&'a str> for char {
     type Output = char;
@@ -28,7 +29,51 @@ impl<'a, 'tmp> Parser<&'a str> for &'tmp str {
             let to = from + self.len();
             Ok((to, &input[from..to]))
         } else {
-            Err((from, Error::Str(*self)))
+            Err((from, Error::Str(self)))
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct NoCase<'tmp>(pub &'tmp str);
+
+// Full case folding, approximated by uppercasing and then lowercasing, so that e.g. "ß" matches
+// "SS" and "K" (Kelvin) matches "k".
+#[inline]
+fn fold(char: char) -> impl Iterator<Item = char> {
+    char.to_uppercase().flat_map(char::to_lowercase)
+}
+
+impl<'a, 'tmp> Parser<&'a str> for NoCase<'tmp> {
+    type Output = &'a str;
+    type Error = Error<'tmp>;
+
+    #[inline(always)]
+    fn parse(&mut self, input: &'a str, from: usize) -> Result<Self::Output, Self::Error> {
+        let to = from + self.0.len();
+        if let Some(bytes) = input.as_bytes().get(from..to) {
+            if bytes.is_ascii() && bytes.eq_ignore_ascii_case(self.0.as_bytes()) {
+                return Ok((to, &input[from..to]));
+            }
+        }
+
+        let mut pattern = self.0.chars().flat_map(fold).peekable();
+        let mut to = from;
+        for char in input[from..].chars() {
+            if pattern.peek().is_none() {
+                break;
+            }
+            for folded in fold(char) {
+                if pattern.next() != Some(folded) {
+                    return Err((from, Error::NoCase(self.0)));
+                }
+            }
+            to += char.len_utf8();
+        }
+        if pattern.peek().is_none() {
+            Ok((to, &input[from..to]))
+        } else {
+            Err((from, Error::NoCase(self.0)))
         }
     }
 }
@@ -47,12 +92,41 @@ where
 
     #[inline(always)]
     fn parse(&mut self, input: &'a str, from: usize) -> Result<Self::Output, Self::Error> {
-        if let Some(char) = input[from..].chars().next() {
-            if self.0(char) {
-                return Ok((from + char.len_utf8(), char));
-            }
+        input::Satisfy(&mut self.0).parse(input, from)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct OneOf<'tmp>(pub &'tmp Class);
+
+impl<'a, 'tmp> Parser<&'a str> for OneOf<'tmp> {
+    type Output = char;
+    type Error = Error<'static>;
+
+    #[inline(always)]
+    fn parse(&mut self, input: &'a str, from: usize) -> Result<Self::Output, Self::Error> {
+        let class = self.0;
+        match input::Satisfy(|char| class.contains(char)).parse(input, from) {
+            Err((from, _)) => Err((from, Error::OneOf(class.clone()))),
+            Ok(ok) => Ok(ok),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct NoneOf<'tmp>(pub &'tmp Class);
+
+impl<'a, 'tmp> Parser<&'a str> for NoneOf<'tmp> {
+    type Output = char;
+    type Error = Error<'static>;
+
+    #[inline(always)]
+    fn parse(&mut self, input: &'a str, from: usize) -> Result<Self::Output, Self::Error> {
+        let class = self.0;
+        match input::Satisfy(|char| !class.contains(char)).parse(input, from) {
+            Err((from, _)) => Err((from, Error::NoneOf(class.clone()))),
+            Ok(ok) => Ok(ok),
         }
-        Err((from, Error::Satisfy))
     }
 }
 
@@ -70,12 +144,7 @@ where
 
     #[inline(always)]
     fn parse(&mut self, input: &'a str, from: usize) -> Result<Self::Output, Self::Error> {
-        let mut chars = input[from..].chars();
-        let to = match chars.by_ref().find(|&char| !self.0(char)) {
-            Some(char) => input.len() - chars.as_str().len() - char.len_utf8(),
-            None => input.len(),
-        };
-        Ok((to, &input[from..to]))
+        input::TakeWhile(&mut self.0).parse(input, from)
     }
 }
 
@@ -93,13 +162,102 @@ where
 
     #[inline(always)]
     fn parse(&mut self, input: &'a str, from: usize) -> Result<Self::Output, Self::Error> {
-        match TakeWhile(&mut self.0).parse(input, from) {
-            Ok((_, "")) => Err((from, Error::TakeWhile1)),
+        input::TakeWhile1(&mut self.0).parse(input, from)
+    }
+}
+
+// `TakeWhile` and `TakeWhile1` over the members of a class, failing with `Error::OneOf`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TakeWhileOneOf<'tmp>(pub &'tmp Class);
+
+impl<'a, 'tmp> Parser<&'a str> for TakeWhileOneOf<'tmp> {
+    type Output = &'a str;
+    type Error = Error<'static>;
+
+    #[inline(always)]
+    fn parse(&mut self, input: &'a str, from: usize) -> Result<Self::Output, Self::Error> {
+        let class = self.0;
+        input::TakeWhile(|char| class.contains(char)).parse(input, from)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TakeWhile1OneOf<'tmp>(pub &'tmp Class);
+
+impl<'a, 'tmp> Parser<&'a str> for TakeWhile1OneOf<'tmp> {
+    type Output = &'a str;
+    type Error = Error<'static>;
+
+    #[inline(always)]
+    fn parse(&mut self, input: &'a str, from: usize) -> Result<Self::Output, Self::Error> {
+        match TakeWhileOneOf(self.0).parse(input, from) {
+            Ok((_, "")) => Err((from, Error::OneOf(self.0.clone()))),
             otherwise => otherwise,
         }
     }
 }
 
+// Takes everything up to the first occurrence of a character or string, or up to the end of input
+// if there is none.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TakeUntil<T>(pub T);
+
+impl<'a> Parser<&'a str> for TakeUntil<char> {
+    type Output = &'a str;
+    type Error = Error<'static>;
+
+    #[inline(always)]
+    fn parse(&mut self, input: &'a str, from: usize) -> Result<Self::Output, Self::Error> {
+        let rest = &input[from..];
+        let len = if self.0.is_ascii() {
+            scan::find_byte(rest.as_bytes(), self.0 as u8)
+        } else {
+            scan::find(rest.as_bytes(), self.0.encode_utf8(&mut [0; 4]).as_bytes())
+        };
+        let len = len.unwrap_or(rest.len());
+        Ok((from + len, &rest[..len]))
+    }
+}
+
+impl<'a> Parser<&'a str> for TakeUntil<&str> {
+    type Output = &'a str;
+    type Error = Error<'static>;
+
+    #[inline(always)]
+    fn parse(&mut self, input: &'a str, from: usize) -> Result<Self::Output, Self::Error> {
+        let rest = &input[from..];
+        let len = scan::find(rest.as_bytes(), self.0.as_bytes()).unwrap_or(rest.len());
+        Ok((from + len, &rest[..len]))
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TakeUntilAny<const N: usize>(pub [char; N]);
+
+impl<'a, const N: usize> Parser<&'a str> for TakeUntilAny<N> {
+    type Output = &'a str;
+    type Error = Error<'static>;
+
+    #[inline(always)]
+    fn parse(&mut self, input: &'a str, from: usize) -> Result<Self::Output, Self::Error> {
+        let rest = &input[from..];
+        // ASCII bytes never occur inside a multi-byte character, so a byte search is exact.
+        let len = if self.0.iter().all(char::is_ascii) {
+            let mut needles = [0; N];
+            for (needle, char) in needles.iter_mut().zip(&self.0) {
+                *needle = *char as u8;
+            }
+            scan::find_any(rest.as_bytes(), &needles)
+        } else {
+            rest.char_indices()
+                .find(|(_, char)| self.0.contains(char))
+                .map(|(index, _)| index)
+        };
+        let len = len.unwrap_or(rest.len());
+        Ok((from + len, &rest[..len]))
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct Capture<P>(pub P);
 
@@ -112,8 +270,23 @@ where
 
     #[inline(always)]
     fn parse(&mut self, input: &'a str, from: usize) -> Result<Self::Output, Self::Error> {
-        let (to, _) = self.0.parse(input, from)?;
-        Ok((to, &input[from..to]))
+        input::Capture(self.0.by_ref()).parse(input, from)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SkipUntil<P>(pub P);
+
+impl<'a, P> Parser<&'a str> for SkipUntil<P>
+where
+    P: Parser<&'a str>,
+{
+    type Output = &'a str;
+    type Error = P::Error;
+
+    #[inline(always)]
+    fn parse(&mut self, input: &'a str, from: usize) -> Result<Self::Output, Self::Error> {
+        input::SkipUntil(self.0.by_ref()).parse(input, from)
     }
 }
 
@@ -126,11 +299,7 @@ impl<'a> Parser<&'a str> for Any {
 
     #[inline(always)]
     fn parse(&mut self, input: &'a str, from: usize) -> Result<Self::Output, Self::Error> {
-        if let Some(char) = input[from..].chars().next() {
-            Ok((from + char.len_utf8(), char))
-        } else {
-            Err((from, Error::Any))
-        }
+        input::Any.parse(input, from)
     }
 }
 
@@ -143,11 +312,7 @@ impl<'a> Parser<&'a str> for Peek {
 
     #[inline(always)]
     fn parse(&mut self, input: &'a str, from: usize) -> Result<Self::Output, Self::Error> {
-        if let Some(char) = input[from..].chars().next() {
-            Ok((from, char))
-        } else {
-            Err((from, Error::Peek))
-        }
+        input::Peek.parse(input, from)
     }
 }
 
@@ -160,10 +325,6 @@ impl<'a> Parser<&'a str> for End {
 
     #[inline(always)]
     fn parse(&mut self, input: &'a str, from: usize) -> Result<Self::Output, Self::Error> {
-        if input.len() == from {
-            Ok((from, ()))
-        } else {
-            Err((from, Error::End))
-        }
+        input::End.parse(input, from)
     }
 }
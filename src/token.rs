@@ -0,0 +1,153 @@
+use std::fmt::Debug;
+
+use crate::error::{Error, Shown};
+use crate::input::{self, Input};
+use crate::span::{HasSpan, Span};
+use crate::{Parser, Result};
+
+// A token slice whose tokens are taken by reference, so that the `input` primitives work for tokens
+// that aren't `Copy`.
+struct Tokens<'a, T>(&'a [T]);
+
+impl<'a, T> Clone for Tokens<'a, T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for Tokens<'a, T> {}
+
+impl<'a, T> Input for Tokens<'a, T> {
+    type Token = &'a T;
+    type Slice = &'a [T];
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[inline(always)]
+    fn token(&self, at: usize) -> Option<(Self::Token, usize)> {
+        self.0.get(at).map(|token| (token, at + 1))
+    }
+
+    #[inline(always)]
+    fn slice(&self, from: usize, to: usize) -> Self::Slice {
+        &self.0[from..to]
+    }
+
+    #[inline(always)]
+    fn advance(&self, from: usize, count: usize) -> Option<usize> {
+        Some(from + count).filter(|&to| to <= self.0.len())
+    }
+}
+
+#[inline(always)]
+fn shown<T>(token: &T) -> Shown<'_>
+where
+    T: Debug + Sync,
+{
+    Shown::Debug(token)
+}
+
+// The expected token is borrowed, like the literal of `str::Str`, so that errors can refer to it
+// without formatting it up front.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Token<'tmp, T>(pub &'tmp T);
+
+impl<'a: 'tmp, 'tmp, T> Parser<&'a [T]> for Token<'tmp, T>
+where
+    T: PartialEq + Debug + Sync,
+{
+    type Output = &'a T;
+    type Error = Error<'tmp>;
+
+    #[inline(always)]
+    fn parse(&mut self, input: &'a [T], from: usize) -> Result<Self::Output, Self::Error> {
+        match input.get(from) {
+            Some(token) if token == self.0 => Ok((from + 1, token)),
+            found => Err((from, Error::Token(Some(shown(self.0)), found.map(shown)))),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Satisfy<F>(pub F);
+
+impl<'a, T, F> Parser<&'a [T]> for Satisfy<F>
+where
+    F: FnMut(&T) -> bool,
+{
+    type Output = &'a T;
+    type Error = Error<'a>;
+
+    #[inline(always)]
+    fn parse(&mut self, input: &'a [T], from: usize) -> Result<Self::Output, Self::Error> {
+        input::Satisfy(&mut self.0).parse(Tokens(input), from)
+    }
+}
+
+// Matches a token for which `F` returns `Some`, e.g. one enum variant, and outputs its payload.
+// See also `select!`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Select<F>(pub F);
+
+impl<'a, T, F, Output> Parser<&'a [T]> for Select<F>
+where
+    T: Debug + Sync,
+    F: FnMut(&'a T) -> Option<Output>,
+{
+    type Output = Output;
+    type Error = Error<'a>;
+
+    #[inline(always)]
+    fn parse(&mut self, input: &'a [T], from: usize) -> Result<Self::Output, Self::Error> {
+        match input.get(from).and_then(&mut self.0) {
+            Some(output) => Ok((from + 1, output)),
+            None => Err((from, Error::Token(None, input.get(from).map(shown)))),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Any;
+
+impl<'a, T> Parser<&'a [T]> for Any {
+    type Output = &'a T;
+    type Error = Error<'a>;
+
+    #[inline(always)]
+    fn parse(&mut self, input: &'a [T], from: usize) -> Result<Self::Output, Self::Error> {
+        input::Any.parse(Tokens(input), from)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct End;
+
+impl<'a, T> Parser<&'a [T]> for End {
+    type Output = ();
+    type Error = Error<'a>;
+
+    #[inline(always)]
+    fn parse(&mut self, input: &'a [T], from: usize) -> Result<Self::Output, Self::Error> {
+        input::End.parse(Tokens(input), from)
+    }
+}
+
+// Maps a failure at a token index back to the source span of that token, or to the end of the
+// last token if parsing ran out of tokens.
+pub fn locate<T, E>(tokens: &[T], (index, error): (usize, E)) -> (Span, E)
+where
+    T: HasSpan,
+{
+    let span = match tokens.get(index) {
+        Some(token) => token.span(),
+        None => {
+            let end = tokens.last().map_or(0, |token| token.span().end);
+            Span::new(end, end)
+        }
+    };
+    (span, error)
+}
@@ -0,0 +1,238 @@
+use std::io::{self, BufRead, Read};
+
+use crate::error::{Incomplete, Needed};
+use crate::partial::{Partial, ReadError};
+use crate::Parser;
+
+// Runs partial parsers over a buffered stream. Each attempt parses the whole window once, marked
+// complete only at the end of the stream; an `Incomplete` failure grows the window (at least
+// doubling it) and retries, anything else is final. Unlike `partial::Reader`, outputs may borrow
+// from the window.
+type Parsed<P, I, E> = Result<Option<<P as Parser<I>>::Output>, ReadError<E>>;
+
+#[derive(Debug)]
+pub struct Window<R> {
+    reader: R,
+    buffer: Vec<u8>,
+    start: usize,
+    offset: usize,
+    eof: bool,
+}
+
+impl<R> Window<R>
+where
+    R: BufRead,
+{
+    #[inline]
+    pub fn new(reader: R) -> Self {
+        Window {
+            reader,
+            buffer: Vec::new(),
+            start: 0,
+            offset: 0,
+            eof: false,
+        }
+    }
+
+    #[inline]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    #[inline]
+    pub fn buffer(&self) -> &[u8] {
+        &self.buffer[self.start..]
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    pub fn parse<'w, P, E>(&'w mut self, parser: &mut P) -> Parsed<P, Partial<&'w [u8]>, E>
+    where
+        P: for<'b> Parser<Partial<&'b [u8]>, Error = E>,
+        E: Incomplete,
+    {
+        let mut want = 1;
+        loop {
+            self.fill(want)?;
+            let available = self.buffer.len() - self.start;
+            if available == 0 {
+                return Ok(None);
+            }
+            let input = Partial {
+                input: self.window(),
+                complete: self.eof,
+            };
+            match parser.parse(input, 0) {
+                Ok((to, output)) => return Ok(Some(self.advance(to, output))),
+                Err((from, error)) => match error.needed() {
+                    Some(needed) if !self.eof => want = grow(available, needed),
+                    _ => return Err(ReadError::Parse(self.offset + from, error)),
+                },
+            }
+        }
+    }
+
+    // Like `parse`, but for `str` parsers. A character split across reads stays in the buffer until
+    // the rest of it arrives.
+    pub fn parse_str<'w, P, E>(&'w mut self, parser: &mut P) -> Parsed<P, Partial<&'w str>, E>
+    where
+        P: for<'b> Parser<Partial<&'b str>, Error = E>,
+        E: Incomplete,
+    {
+        let mut want = 1;
+        loop {
+            self.fill(want)?;
+            let available = self.buffer.len() - self.start;
+            if available == 0 {
+                return Ok(None);
+            }
+            let input = Partial {
+                input: decode(self.window(), self.eof)?,
+                complete: self.eof,
+            };
+            match parser.parse(input, 0) {
+                Ok((to, output)) => return Ok(Some(self.advance(to, output))),
+                Err((from, error)) => match error.needed() {
+                    Some(needed) if !self.eof => want = grow(available, needed),
+                    _ => return Err(ReadError::Parse(self.offset + from, error)),
+                },
+            }
+        }
+    }
+
+    // The unconsumed part of the buffer, borrowed for as long as `self` is. The borrow checker
+    // can't see that a failed attempt releases it before the buffer is refilled, so it's detached
+    // from `self` here: outputs only escape on success, where `&'w mut self` keeps the buffer
+    // untouched for as long as they live, and errors can't borrow from any particular window since
+    // the parser has the same error type for every input lifetime.
+    #[inline]
+    fn window<'w>(&self) -> &'w [u8] {
+        let window: *const [u8] = &self.buffer[self.start..];
+        unsafe { &*window }
+    }
+
+    #[inline]
+    fn advance<O>(&mut self, to: usize, output: O) -> O {
+        self.start += to;
+        self.offset += to;
+        output
+    }
+
+    // Reads until at least `want` bytes are buffered past `start`, or the reader runs dry.
+    fn fill(&mut self, want: usize) -> io::Result<()> {
+        if self.eof || self.buffer.len() - self.start >= want {
+            return Ok(());
+        }
+        self.buffer.drain(..self.start);
+        self.start = 0;
+
+        while !self.eof && self.buffer.len() < want {
+            let chunk = loop {
+                match self.reader.fill_buf() {
+                    Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+                    otherwise => break otherwise?,
+                }
+            };
+            let len = chunk.len();
+            self.buffer.extend_from_slice(chunk);
+            self.reader.consume(len);
+            if len == 0 {
+                self.eof = true;
+            }
+        }
+        Ok(())
+    }
+}
+
+// Doubling keeps the total work of retrying a long item linear in its length.
+#[inline]
+fn grow(available: usize, needed: Needed) -> usize {
+    let needed = match needed {
+        Needed::Size(size) => size,
+        Needed::Unknown => 1,
+    };
+    (available + needed).max(available * 2)
+}
+
+#[inline]
+fn decode(bytes: &[u8], eof: bool) -> io::Result<&str> {
+    match std::str::from_utf8(bytes) {
+        Ok(input) => Ok(input),
+        Err(error) if error.error_len().is_none() && !eof => {
+            Ok(std::str::from_utf8(&bytes[..error.valid_up_to()]).unwrap())
+        }
+        Err(error) => Err(io::Error::new(io::ErrorKind::InvalidData, error)),
+    }
+}
+
+// Adapts an iterator of chunks (`Vec<u8>`, `String`, `&[u8]`, ...) into a `BufRead`.
+#[derive(Debug)]
+pub struct Chunks<I>
+where
+    I: Iterator,
+{
+    chunks: I,
+    chunk: Option<I::Item>,
+    consumed: usize,
+}
+
+impl<I> Chunks<I>
+where
+    I: Iterator,
+    I::Item: AsRef<[u8]>,
+{
+    #[inline]
+    pub fn new<C>(chunks: C) -> Self
+    where
+        C: IntoIterator<IntoIter = I>,
+    {
+        Chunks {
+            chunks: chunks.into_iter(),
+            chunk: None,
+            consumed: 0,
+        }
+    }
+}
+
+impl<I> Read for Chunks<I>
+where
+    I: Iterator,
+    I::Item: AsRef<[u8]>,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let available = self.fill_buf()?;
+        let n = available.len().min(buf.len());
+        buf[..n].copy_from_slice(&available[..n]);
+        self.consume(n);
+        Ok(n)
+    }
+}
+
+impl<I> BufRead for Chunks<I>
+where
+    I: Iterator,
+    I::Item: AsRef<[u8]>,
+{
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        while match &self.chunk {
+            Some(chunk) => self.consumed == chunk.as_ref().len(),
+            None => true,
+        } {
+            match self.chunks.next() {
+                Some(chunk) => {
+                    self.chunk = Some(chunk);
+                    self.consumed = 0;
+                }
+                None => return Ok(&[]),
+            }
+        }
+        Ok(&self.chunk.as_ref().unwrap().as_ref()[self.consumed..])
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.consumed += amt;
+    }
+}
@@ -5,7 +5,7 @@ use munch::error::*;
 mod t;
 
 fn is_alphabetic(u8: u8) -> bool {
-    matches!(u8, b'a'..=b'z' | b'A'..=b'Z')
+    u8.is_ascii_alphabetic()
 }
 
 #[test]
@@ -65,3 +65,55 @@ fn take_while1() {
         },
     }
 }
+
+#[test]
+fn no_case() {
+    t_and_tb! {
+        NoCase(b"GET") => {
+            "" => Err((0, Error::NoCaseBytes(b"GET"))),
+            "GE" => Err((0, Error::NoCaseBytes(b"GET"))),
+            "GET /" => Ok((3, "GET")),
+            "get /" => Ok((3, "get")),
+            "gEt" => Ok((3, "gEt")),
+            "PUT" => Err((0, Error::NoCaseBytes(b"GET"))),
+        },
+        NoCase(b"content-type") => {
+            "Content-Type: text/plain" => Ok((12, "Content-Type")),
+            "CONTENT_TYPE" => Err((0, Error::NoCaseBytes(b"content-type"))),
+        },
+        NoCase("π".as_bytes()) => {
+            "π" => Err((0, Error::NoCaseBytes("π".as_bytes()))),
+        },
+    }
+    assert_eq!(
+        Error::NoCaseBytes(b"GET").to_string(),
+        "expected b\"GET\" (case-insensitive)"
+    );
+}
+
+#[test]
+fn one_of() {
+    use munch::class::Class;
+
+    let class = Class::new().range('a'..='z').char('\u{FF}');
+    t_and_tb! {
+        OneOf(&class) => {
+            "" => Err((0, Error::OneOf(class.clone()))),
+            "p" => Ok((1, b'p')),
+            "ÿ" => Err((0, Error::OneOf(class.clone()))),
+        },
+        NoneOf(&class) => {
+            "" => Err((0, Error::NoneOf(class.clone()))),
+            "P" => Ok((1, b'P')),
+            "p" => Err((0, Error::NoneOf(class.clone()))),
+            "π" => Err((0, Error::NoneOf(class.clone()))),
+        },
+        TakeWhileOneOf(&class) => {
+            "abÿ" => Ok((2, "ab")),
+        },
+        TakeWhile1OneOf(&class) => {
+            "abÿ" => Ok((2, "ab")),
+            "ÿ" => Err((0, Error::OneOf(class.clone()))),
+        },
+    }
+}
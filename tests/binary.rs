@@ -0,0 +1,99 @@
+use munch::error::{BinaryType, Endianness, Error};
+use munch::{Parse, Parser};
+
+#[derive(Debug, PartialEq, munch_macros::BinaryParse)]
+struct Version(u8, u8);
+
+#[derive(Debug, PartialEq, munch_macros::BinaryParse)]
+#[munch(little)]
+struct Header<'a> {
+    magic: [u8; 4],
+    version: Version,
+    #[munch(big)]
+    flags: u16,
+    offset: i32,
+    scale: f32,
+    #[munch(len = u16)]
+    name: &'a [u8],
+    count: u8,
+    #[munch(len = count)]
+    entries: Vec<u16>,
+    pair: [u16; 2],
+}
+
+#[test]
+fn header() {
+    let input: &[u8] = &[
+        b'M', b'N', b'C', b'H', // magic
+        1, 2, // version
+        0x01, 0x02, // flags
+        0xFE, 0xFF, 0xFF, 0xFF, // offset
+        0x00, 0x00, 0xC0, 0x3F, // scale
+        0x03, 0x00, b'a', b'b', b'c', // name
+        2, 0x01, 0x00, 0x02, 0x00, // entries
+        0x03, 0x00, 0x04, 0x00, // pair
+    ];
+
+    assert_eq!(
+        Header::parse(input, 0),
+        Ok((
+            input.len(),
+            Header {
+                magic: *b"MNCH",
+                version: Version(1, 2),
+                flags: 0x0102,
+                offset: -2,
+                scale: 1.5,
+                name: b"abc",
+                count: 2,
+                entries: vec![1, 2],
+                pair: [3, 4],
+            }
+        ))
+    );
+
+    assert_eq!(Header::parse(&input[..2], 0), Err((0, Error::Take(4))));
+    assert_eq!(
+        Header::parse(&input[..7], 0),
+        Err((6, Error::Binary(Endianness::Big, BinaryType::u16)))
+    );
+    assert_eq!(Header::parse(&input[..19], 0), Err((18, Error::Take(3))));
+    assert_eq!(
+        Header::parse(&input[..24], 0),
+        Err((24, Error::Binary(Endianness::Little, BinaryType::u16)))
+    );
+
+    let mut versions = munch::P(Version::parse).repeat(..).collect::<Vec<_>, _>();
+    assert_eq!(
+        versions.parse(&[1, 2, 3, 4][..], 0),
+        Ok((4, vec![Version(1, 2), Version(3, 4)]))
+    );
+}
+
+#[derive(Debug, PartialEq, munch_macros::BinaryParse)]
+struct Packet<'a> {
+    kind: u8,
+    from: u32,
+    to: u32,
+    len: u8,
+    #[munch(len = len)]
+    input: &'a [u8],
+}
+
+#[test]
+fn field_names() {
+    let input: &[u8] = &[7, 0, 0, 0, 1, 0, 0, 0, 2, 3, b'a', b'b', b'c', 0xFF];
+    assert_eq!(
+        Packet::parse(input, 0),
+        Ok((
+            13,
+            Packet {
+                kind: 7,
+                from: 1,
+                to: 2,
+                len: 3,
+                input: b"abc",
+            }
+        ))
+    );
+}
@@ -1,15 +1,16 @@
 use munch::byte::*;
 use munch::error::{BinaryType, Endianness, Error};
+use munch::Parser;
 
 #[macro_use]
 mod t;
 
 fn is_alphabetic(b: u8) -> bool {
-    matches!(b, b'a'..=b'z' | b'A'..=b'Z')
+    b.is_ascii_alphabetic()
 }
 
 fn is_alphanumeric(b: u8) -> bool {
-    is_alphabetic(b) || matches!(b, b'0'..=b'9')
+    b.is_ascii_alphanumeric()
 }
 
 #[test]
@@ -50,6 +51,40 @@ fn satisfy() {
     }
 }
 
+#[test]
+fn one_of() {
+    use munch::class::Class;
+
+    let hex = Class::new()
+        .range('0'..='9')
+        .range('a'..='f')
+        .char('\u{FF}');
+    tb! {
+        OneOf(&hex) => {
+            b"" => Err((0, Error::OneOf(hex.clone()))),
+            b"f" => Ok((1, b'f')),
+            b"\xFF" => Ok((1, 0xFF)),
+            b"g" => Err((0, Error::OneOf(hex.clone()))),
+        },
+        NoneOf(&hex) => {
+            b"" => Err((0, Error::NoneOf(hex.clone()))),
+            b"f" => Err((0, Error::NoneOf(hex.clone()))),
+            b"\xFE" => Ok((1, 0xFE)),
+        },
+        TakeWhile1(|b| hex.contains_byte(b)) => {
+            b"c0ffee!" => Ok((6, &b"c0ffee"[..])),
+        },
+        TakeWhileOneOf(&hex) => {
+            b"!" => Ok((0, &b""[..])),
+            b"c0ffee!" => Ok((6, &b"c0ffee"[..])),
+        },
+        TakeWhile1OneOf(&hex) => {
+            b"c0ffee!" => Ok((6, &b"c0ffee"[..])),
+            b"!" => Err((0, Error::OneOf(hex.clone()))),
+        },
+    }
+}
+
 #[test]
 fn take_while() {
     tb! {
@@ -76,6 +111,49 @@ fn take_while1() {
     }
 }
 
+#[test]
+fn take_until() {
+    tb! {
+        TakeUntil(b'\n') => {
+            b"" => Ok((0, &b""[..])),
+            b"\n" => Ok((0, &b""[..])),
+            b"ab\ncd" => Ok((2, &b"ab"[..])),
+            b"abcdefghijklmnop\nq" => Ok((16, &b"abcdefghijklmnop"[..])),
+            b"abcdefghijklmnopq" => Ok((17, &b"abcdefghijklmnopq"[..])),
+        },
+        TakeUntil(b"-->") => {
+            b"" => Ok((0, &b""[..])),
+            b"a -- b --> c" => Ok((7, &b"a -- b "[..])),
+            b"a -- b -" => Ok((8, &b"a -- b -"[..])),
+            b"-->" => Ok((0, &b""[..])),
+        },
+        TakeUntil(&b""[..]) => {
+            b"ab" => Ok((0, &b""[..])),
+        },
+        TakeUntilAny([b'\r', b'\n', 0xFF]) => {
+            b"" => Ok((0, &b""[..])),
+            b"abcdefgh\r\n" => Ok((8, &b"abcdefgh"[..])),
+            b"abcdefghij\xFF" => Ok((10, &b"abcdefghij"[..])),
+            b"abcdefghijklmnopqrstuvwxyz" => Ok((26, &b"abcdefghijklmnopqrstuvwxyz"[..])),
+        },
+    }
+
+    // Every position of the needle in and around eight-byte words.
+    let input = [b'a'; 40];
+    for at in 0..input.len() {
+        let mut input = input;
+        input[at] = b'\n';
+        assert_eq!(
+            TakeUntil(b'\n').parse(&input[..], 0),
+            Ok((at, &input[..at]))
+        );
+        assert_eq!(
+            TakeUntilAny([b'x', b'\n']).parse(&input[..], 0),
+            Ok((at, &input[..at]))
+        );
+    }
+}
+
 #[test]
 fn capture() {
     tb! {
@@ -90,6 +168,19 @@ fn capture() {
     }
 }
 
+#[test]
+fn skip_until() {
+    tb! {
+        SkipUntil(Satisfy(|b| b == b'r' || b == b'2')) => {
+            b"" => Ok((0, b"".as_ref())),
+            b"p" => Ok((1, b"p".as_ref())),
+            b"pr" => Ok((1, b"p".as_ref())),
+            b"p2r" => Ok((1, b"p".as_ref())),
+            b"r" => Ok((0, b"".as_ref())),
+        },
+    }
+}
+
 #[test]
 fn any() {
     tb! {
@@ -0,0 +1,57 @@
+use munch::class::Class;
+
+#[test]
+fn class() {
+    let ident = Class::new().range('a'..='z').range('A'..='Z').char('_');
+    assert!(ident.contains('a'));
+    assert!(ident.contains('Q'));
+    assert!(ident.contains('_'));
+    assert!(!ident.contains('0'));
+    assert!(!ident.contains('π'));
+    assert!(ident.contains_byte(b'z'));
+    assert!(!ident.contains_byte(0xFF));
+    assert_eq!(ident.to_string(), "[A-Z_a-z]");
+
+    let greek = Class::new().range('α'..='ω').chars("Π");
+    assert!(greek.contains('π'));
+    assert!(greek.contains('Π'));
+    assert!(!greek.contains('a'));
+    assert_eq!(greek.to_string(), "[Πα-ω]");
+
+    let both = ident.clone() | greek.clone();
+    assert!(both.contains('a') && both.contains('π') && !both.contains('0'));
+    assert_eq!(both, greek.union(&ident));
+
+    let not = !ident.clone();
+    assert!(!not.contains('a'));
+    assert!(not.contains('0'));
+    assert!(not.contains('π'));
+    assert!(not.contains_byte(0xFF));
+    assert!(not.contains(char::MAX));
+    assert_eq!(not.to_string(), "[^A-Z_a-z]");
+    assert_eq!(!not, ident);
+
+    // Adjacent and overlapping ranges merge.
+    let digits = Class::new()
+        .range('0'..='4')
+        .range('5'..='9')
+        .range('3'..='6');
+    assert_eq!(digits, Class::new().range('0'..='9'));
+    assert_eq!(digits.to_string(), "[0-9]");
+    assert_eq!(Class::new().chars("ab").to_string(), "[ab]");
+
+    // Surrogates are not characters, so they don't break ranges.
+    let around = Class::new()
+        .range('\0'..='\u{D7FF}')
+        .range('\u{E000}'..=char::MAX);
+    assert_eq!(around, !Class::new());
+    assert_eq!(around.to_string(), "[^]");
+
+    assert!(Class::new().is_empty());
+    assert_eq!(Class::new().to_string(), "[]");
+    assert_eq!(Class::new().range('z'..='a'), Class::new());
+    assert_eq!(
+        Class::new().chars("-]^\\ \t").to_string(),
+        "[\\t \\-\\\\-\\^]"
+    );
+}
@@ -0,0 +1,181 @@
+use munch::error::{self, Error};
+use munch::{Parse, Parser};
+
+fn octet(input: &str, from: usize) -> munch::Result<u8, Error<'static>> {
+    munch::str::TakeWhile1(|c: char| c.is_ascii_digit())
+        .and_then(|digits: &str| digits.parse().map_err(|_| error::message("octet")))
+        .parse(input, from)
+}
+
+fn number(input: &str, from: usize) -> munch::Result<i64, Error<'static>> {
+    munch::str::TakeWhile1(|c: char| c.is_ascii_digit())
+        .and_then(|digits: &str| digits.parse().map_err(|_| error::message("number")))
+        .parse(input, from)
+}
+
+#[derive(Debug, PartialEq, munch_macros::Parse)]
+#[munch(sep = ".")]
+struct Ip(
+    #[munch(with = octet)] u8,
+    #[munch(with = octet)] u8,
+    #[munch(with = octet)] u8,
+    #[munch(with = octet)] u8,
+);
+
+#[test]
+fn ip() {
+    assert_eq!(Ip::parse("127.0.0.1", 0), Ok((9, Ip(127, 0, 0, 1))));
+    assert_eq!(Ip::parse("127.0.0.1.", 0), Ok((9, Ip(127, 0, 0, 1))));
+    assert_eq!(Ip::parse("127.0.0", 0), Err((7, Error::Str("."))));
+    assert_eq!(
+        Ip::parse("127.0.256.1", 0),
+        Err((9, error::message("octet")))
+    );
+}
+
+#[derive(Debug, PartialEq, munch_macros::Parse)]
+#[munch(before = "(", after = ")")]
+struct Point {
+    #[munch(with = number)]
+    x: i64,
+    #[munch(before = ", ", with = number)]
+    y: i64,
+}
+
+#[derive(Debug, PartialEq, munch_macros::Parse)]
+enum Value {
+    #[munch(before = "true")]
+    True,
+    #[munch(before = "false")]
+    False,
+    Point(Point),
+    #[munch(before = "[", after = "]")]
+    List(#[munch(repeat, sep = ",")] Vec<Value>),
+    Number(#[munch(with = number)] i64),
+}
+
+#[test]
+fn value() {
+    use Value::*;
+
+    assert_eq!(Value::parse("true", 0), Ok((4, True)));
+    assert_eq!(Value::parse("false", 0), Ok((5, False)));
+    assert_eq!(Value::parse("42", 0), Ok((2, Number(42))));
+    assert_eq!(
+        Value::parse("(1, 2)", 0),
+        Ok((6, Point(self::Point { x: 1, y: 2 })))
+    );
+    assert_eq!(
+        Value::parse("[1,true,[],(3, 4)]", 0),
+        Ok((
+            18,
+            List(vec![
+                Number(1),
+                True,
+                List(vec![]),
+                Point(self::Point { x: 3, y: 4 })
+            ])
+        ))
+    );
+    assert_eq!(Value::parse("(1,2)", 0), Err((2, Error::Str(", "))));
+    assert_eq!(
+        Value::parse("x", 0),
+        Err((
+            0,
+            Error::Expected(vec![
+                Error::Str("true"),
+                Error::Str("false"),
+                Error::Str("("),
+                Error::Str("["),
+                Error::TakeWhile1,
+            ])
+        ))
+    );
+
+    let mut values = munch::P(Value::parse).repeat(1..).join(' ');
+    assert_eq!(values.parse("1 true", 0), Ok((6, vec![Number(1), True])));
+}
+
+fn ident(input: &str, from: usize) -> munch::Result<String, Error<'static>> {
+    munch::str::TakeWhile1(char::is_alphabetic)
+        .map(String::from)
+        .parse(input, from)
+}
+
+// `Call` and `Var` both start with an identifier.
+#[derive(Debug, PartialEq, munch_macros::Parse)]
+enum Expr {
+    Call(#[munch(with = ident, after = "()")] String),
+    Var(#[munch(with = ident)] String),
+}
+
+#[test]
+fn shared_prefix() {
+    assert_eq!(Expr::parse("f()", 0), Ok((3, Expr::Call("f".into()))));
+    assert_eq!(Expr::parse("x", 0), Ok((1, Expr::Var("x".into()))));
+    assert_eq!(Expr::parse("x(", 0), Ok((1, Expr::Var("x".into()))));
+    assert_eq!(Expr::parse("1", 0), Err((0, Error::TakeWhile1)));
+}
+
+#[cfg(feature = "macros")]
+#[test]
+fn reexport() {
+    #[derive(Debug, PartialEq, munch::Parse)]
+    enum Sign {
+        #[munch(before = "+")]
+        Plus,
+        #[munch(before = "-")]
+        Minus,
+    }
+
+    assert_eq!(Sign::parse("-", 0), Ok((1, Sign::Minus)));
+    assert_eq!(
+        Sign::parse("*", 0),
+        Err((0, Error::Expected(vec![Error::Str("+"), Error::Str("-")])))
+    );
+}
+
+#[derive(Debug, PartialEq, munch_macros::Parse)]
+#[munch(bytes, before = "KV")]
+struct Pairs {
+    #[munch(repeat = 1.., with = pair)]
+    pairs: Vec<(u8, u8)>,
+}
+
+fn pair(input: &[u8], from: usize) -> munch::Result<(u8, u8), Error<'static>> {
+    (munch::byte::Any, munch::byte::Any).parse(input, from)
+}
+
+#[test]
+fn bytes() {
+    assert_eq!(
+        Pairs::parse(b"KVabcd".as_ref(), 0),
+        Ok((
+            6,
+            Pairs {
+                pairs: vec![(b'a', b'b'), (b'c', b'd')]
+            }
+        ))
+    );
+    assert_eq!(Pairs::parse(b"KV".as_ref(), 0), Err((2, Error::Any)));
+    assert_eq!(
+        Pairs::parse(b"kv".as_ref(), 0),
+        Err((0, Error::Bytes(b"KV")))
+    );
+}
+
+#[derive(Debug, PartialEq, munch_macros::Parse)]
+#[munch(sep = ",")]
+struct Sample(u16, i8, f64);
+
+#[test]
+fn numbers() {
+    assert_eq!(
+        Sample::parse("8080,-3,0.25", 0),
+        Ok((12, Sample(8080, -3, 0.25)))
+    );
+    assert_eq!(
+        Sample::parse("8080,-300,0.25", 0),
+        Err((9, Error::Number(error::Number::Overflow("i8"))))
+    );
+}
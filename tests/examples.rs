@@ -0,0 +1,46 @@
+#[path = "../examples/clojure.rs"]
+mod clojure;
+
+#[path = "../examples/ini.rs"]
+mod ini;
+
+use munch::error::{Error, Number};
+
+#[test]
+fn clojure() {
+    use clojure::Value::*;
+
+    assert_eq!(
+        clojure::parse(clojure::EXAMPLE).map(|values| values.len()),
+        Ok(3)
+    );
+
+    assert_eq!(
+        clojure::parse("(+5 -5 +-5 + -)"),
+        Ok(vec![List(vec![
+            Integer(5),
+            Integer(-5),
+            Symbol("+-5"),
+            Symbol("+"),
+            Symbol("-")
+        ])])
+    );
+    assert_eq!(
+        clojure::parse("(1 99999999999999999999)"),
+        Err((23, Error::Number(Number::Overflow("i64"))))
+    );
+}
+
+#[test]
+fn ini() {
+    let example = ini::parse(ini::EXAMPLE).unwrap();
+    assert_eq!(example["owner"]["name"], "John Doe");
+    assert_eq!(example["database"]["port"], "143");
+
+    let (from, error) = ini::parse("[database]\nport 143\n").unwrap_err();
+    assert_eq!(from, 16);
+    assert_eq!(
+        error.to_string(),
+        "in section [database] → in key 'port' → expected '='"
+    );
+}
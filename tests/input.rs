@@ -0,0 +1,95 @@
+use munch::error::Error;
+use munch::input::*;
+use munch::{Parser, P};
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Token {
+    Number(i64),
+    Plus,
+    Minus,
+}
+
+#[test]
+fn slice() {
+    use Token::*;
+
+    let input = [Number(1), Plus, Number(2), Minus, Number(3)];
+    let input = input.as_ref();
+
+    let mut number = Satisfy(|token| matches!(token, Number(_)));
+    assert_eq!(number.parse(input, 0), Ok((1, Number(1))));
+    assert_eq!(number.parse(input, 1), Err((1, Error::Satisfy)));
+    assert_eq!(number.parse(input, 5), Err((5, Error::Satisfy)));
+
+    let mut expr = Capture((
+        number,
+        Satisfy(|token| token == Plus || token == Minus),
+        number,
+    ));
+    assert_eq!(expr.parse(input, 0), Ok((3, &input[..3])));
+    assert_eq!(expr.parse(input, 2), Ok((5, &input[2..])));
+
+    assert_eq!(Any.parse(input, 1), Ok((2, Plus)));
+    assert_eq!(Peek.parse(input, 1), Ok((1, Plus)));
+    assert_eq!(Take(2).parse(input, 1), Ok((3, &input[1..3])));
+    assert_eq!(Take(2).parse(input, 4), Err((4, Error::Take(2))));
+    assert_eq!(
+        TakeWhile1(|token| token != Minus).parse(input, 0),
+        Ok((3, &input[..3]))
+    );
+    assert_eq!(
+        SkipUntil(Satisfy(|token| token == Minus)).parse(input, 0),
+        Ok((3, &input[..3]))
+    );
+    assert_eq!(End.parse(input, 5), Ok((5, ())));
+}
+
+#[test]
+fn str() {
+    let mut word = TakeWhile1(char::is_alphabetic);
+    assert_eq!(word.parse("πr² h", 0), Ok((3, "πr")));
+    assert_eq!(word.parse("πr² h", 3), Err((3, Error::TakeWhile1)));
+    assert_eq!(Take(2).parse("πr²", 0), Ok((3, "πr")));
+    assert_eq!(Take(4).parse("πr²", 0), Err((0, Error::Take(4))));
+    assert_eq!(Any.parse("πr²", 3), Ok((5, '²')));
+}
+
+// Words of a sentence as tokens, with `&str` slices.
+#[derive(Copy, Clone, Debug)]
+struct Words<'a>(&'a str);
+
+impl<'a> Input for Words<'a> {
+    type Token = &'a str;
+    type Slice = &'a str;
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn token(&self, at: usize) -> Option<(Self::Token, usize)> {
+        let rest = self.0.get(at..).filter(|rest| !rest.is_empty())?;
+        let end = rest.find(' ').unwrap_or(rest.len());
+        let next = (at + end + 1).min(self.0.len());
+        Some((&rest[..end], next))
+    }
+
+    fn slice(&self, from: usize, to: usize) -> Self::Slice {
+        &self.0[from..to]
+    }
+}
+
+#[test]
+fn custom() {
+    let input = Words("the quick brown fox");
+    assert_eq!(Any.parse(input, 0), Ok((4, "the")));
+    assert_eq!(Take(2).parse(input, 4), Ok((16, "quick brown ")));
+    assert_eq!(
+        TakeWhile(|word: &str| word.len() <= 5).parse(input, 0),
+        Ok((19, "the quick brown fox"))
+    );
+    assert_eq!(
+        Capture(P(Satisfy(|word| word == "the")) >> Any).parse(input, 0),
+        Ok((10, "the quick "))
+    );
+    assert_eq!(End.parse(input, 19), Ok((19, ())));
+}
@@ -0,0 +1,83 @@
+use munch::error::Error;
+use munch::keywords::*;
+use munch::Parser;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Kw {
+    In,
+    Int,
+    Into,
+    If,
+}
+
+#[test]
+fn keywords() {
+    let mut p = Keywords::new(vec!["in", "int", "if", "into"]);
+    assert_eq!(p.len(), 4);
+    assert_eq!(p.parse("in", 0), Ok((2, "in")));
+    assert_eq!(p.parse("int", 0), Ok((3, "int")));
+    assert_eq!(p.parse("inty", 0), Ok((3, "int")));
+    assert_eq!(p.parse("into", 0), Ok((4, "into")));
+    assert_eq!(p.parse("🐱if", 4), Ok((6, "if")));
+    assert_eq!(
+        p.parse("i", 0),
+        Err((
+            0,
+            Error::Expected(vec![
+                Error::Str("in"),
+                Error::Str("int"),
+                Error::Str("if"),
+                Error::Str("into"),
+            ])
+        ))
+    );
+    assert_eq!(
+        p.parse("", 0).unwrap_err().1.to_string(),
+        "expected \"in\", \"int\", \"if\" or \"into\""
+    );
+
+    let mut p = Keywords::new(vec!["πr", "π", "πr²"]);
+    assert_eq!(p.parse("πr²", 0), Ok((5, "πr²")));
+    assert_eq!(p.parse("πr2", 0), Ok((3, "πr")));
+
+    let mut p = Keywords::new(vec!["only"]);
+    assert_eq!(p.parse("on", 0), Err((0, Error::Str("only"))));
+}
+
+#[test]
+fn with_values() {
+    use Kw::*;
+
+    let mut p = Keywords::with_values(vec![("in", In), ("int", Int), ("into", Into), ("if", If)])
+        .map(Some)
+        .or(munch::str::Any.map(|_| None));
+    assert_eq!(p.parse("int", 0), Ok((3, Some(Int))));
+    assert_eq!(p.parse("ix", 0), Ok((1, None)));
+
+    let mut p = Keywords::with_values(vec![("a", 1), ("a", 2)]);
+    assert_eq!(p.len(), 1);
+    assert_eq!(p.parse("a", 0), Ok((1, 2)));
+    assert_eq!(p.parse("b", 0), Err((0, Error::Str("a"))));
+}
+
+#[test]
+fn bytes() {
+    let mut p = Keywords::new(vec![b"GET".as_ref(), b"GETS", b"\xFF"]);
+    assert_eq!(p.parse(b"GET /".as_ref(), 0), Ok((3, b"GET".as_ref())));
+    assert_eq!(p.parse(b"GETS".as_ref(), 0), Ok((4, b"GETS".as_ref())));
+    assert_eq!(p.parse(b"\xFF".as_ref(), 0), Ok((1, b"\xFF".as_ref())));
+
+    let mut p = Keywords::with_values(vec![(b"GET", 1), (b"PUT", 2)]);
+    assert_eq!(p.parse(b"PUT".as_ref(), 0), Ok((3, 2)));
+    assert_eq!(
+        p.parse(b"POST".as_ref(), 0),
+        Err((
+            0,
+            Error::Expected(vec![Error::Bytes(b"GET"), Error::Bytes(b"PUT")])
+        ))
+    );
+
+    let mut p = Keywords::<&str, &str>::new(vec![]);
+    assert!(p.is_empty());
+    assert_eq!(p.parse("x", 0), Err((0, Error::Expected(vec![]))));
+}
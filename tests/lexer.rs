@@ -0,0 +1,101 @@
+use munch::error::{Error, Shown};
+use munch::lexer::Lexer;
+use munch::span::Span;
+use munch::str::*;
+use munch::token::{locate, Select};
+use munch::{select, Parser};
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Kind {
+    Keyword,
+    Ident,
+    Number,
+    Op,
+}
+
+fn lexer() -> Lexer<'static, Kind> {
+    Lexer::new()
+        .keywords(Kind::Keyword, ["in", "int", "let"])
+        .token(Kind::Number, TakeWhile1(|ch: char| ch.is_ascii_digit()))
+        .token(
+            Kind::Ident,
+            TakeWhile1(|ch| ch.is_alphanumeric() || ch == '_'),
+        )
+        .token(Kind::Op, "=")
+        .token(Kind::Op, "==")
+        .token(Kind::Op, '+')
+        .skip(TakeWhile1(char::is_whitespace))
+        .skip(("//".p(), TakeWhile(|ch| ch != '\n')))
+}
+
+#[test]
+fn tokens() {
+    use Kind::*;
+
+    let mut lexer = lexer();
+    let tokens = lexer
+        .tokens("let int_ = 4 // four\nin int == π2+ 1")
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(
+        tokens,
+        [
+            (Span::new(0, 3), Keyword, "let"),
+            (Span::new(4, 8), Ident, "int_"),
+            (Span::new(9, 10), Op, "="),
+            (Span::new(11, 12), Number, "4"),
+            (Span::new(21, 23), Keyword, "in"),
+            (Span::new(24, 27), Keyword, "int"),
+            (Span::new(28, 30), Op, "=="),
+            (Span::new(31, 34), Ident, "π2"),
+            (Span::new(34, 35), Op, "+"),
+            (Span::new(36, 37), Number, "1"),
+        ]
+    );
+}
+
+#[test]
+fn error() {
+    let mut lexer = lexer();
+    let mut tokens = lexer.tokens("let x = 1 ? 2");
+    assert_eq!(tokens.by_ref().count(), 5);
+    assert_eq!(tokens.offset(), 10);
+
+    let mut tokens = lexer.tokens("let x ?");
+    assert_eq!(
+        tokens.nth(2),
+        Some(Err((6, Error::Token(None, Some(Shown::Char('?'))))))
+    );
+    assert_eq!(tokens.next(), None);
+}
+
+#[test]
+fn parse() {
+    let mut lexer = lexer();
+    let source = "let x = 1 + 2 +";
+    let tokens = lexer.tokens(source).collect::<Result<Vec<_>, _>>().unwrap();
+
+    let value = Select(|token: &(Span, Kind, &str)| match token {
+        (_, Kind::Ident, name) => Some(name.len() as i64),
+        (_, Kind::Number, number) => number.parse().ok(),
+        _ => None,
+    });
+    let mut sum = value
+        .repeat(1..)
+        .join(select!((_, Kind::Op, "+") => ()))
+        .fold(|| 0, |acc, x| acc + x, |acc, _, x| acc + x);
+    let mut binding = (
+        select!((_, Kind::Keyword, "let") => ()),
+        value,
+        select!((_, Kind::Op, "=") => ()),
+        sum.by_ref(),
+    );
+
+    let error = binding.parse(&tokens, 0).unwrap_err();
+    assert_eq!(error, (7, Error::Token(None, None)));
+    assert_eq!(
+        locate(&tokens, error),
+        (Span::new(15, 15), Error::Token(None, None))
+    );
+    assert_eq!(sum.parse(&tokens[..6], 3), Ok((6, 3)));
+}
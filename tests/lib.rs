@@ -74,7 +74,7 @@ fn pack() {
     }
 
     let number = |input, from, &(min, max): &(u32, u32)| {
-        TakeWhile1(|ch| ch.is_digit(10))
+        TakeWhile1(|ch| ch.is_ascii_digit())
             .map_err(|_| Error::NotANumber)
             .and_then(|str| match str.parse() {
                 Ok(number) => {
@@ -185,6 +185,181 @@ fn or() {
     }
 }
 
+#[test]
+fn or_merge() {
+    #[derive(Debug, PartialEq)]
+    struct Expected(Vec<&'static str>);
+
+    impl munch::error::Merge for Expected {
+        fn merge(mut self, other: Self) -> Self {
+            self.0.extend(other.0);
+            self
+        }
+    }
+
+    let digit = |input, from| {
+        Satisfy(|ch: char| ch.is_ascii_digit())
+            .map_err(|_| Expected(vec!["digit"]))
+            .parse(input, from)
+    };
+    let letter = |input, from| {
+        Satisfy(char::is_alphabetic)
+            .map_err(|_| Expected(vec!["letter"]))
+            .parse(input, from)
+    };
+
+    t! {
+        'a'.alt('b').alt('a'.alt("cd".map(|_| 'c'))) => {
+            "" => Err((0, Error::Expected(vec![Error::Char('a'), Error::Char('b'), Error::Str("cd")]))),
+            "c" => Err((0, Error::Expected(vec![Error::Char('a'), Error::Char('b'), Error::Str("cd")]))),
+            "cd" => Ok((2, 'c')),
+        },
+        ('a'.p() >> 'b').alt('c') => {
+            "a" => Err((1, Error::Char('b'))),
+            "d" => Err((0, Error::Expected(vec![Error::Char('a'), Error::Char('c')]))),
+        },
+        'a'.alt('b').alt('a') => {
+            "" => Err((0, Error::Expected(vec![Error::Char('a'), Error::Char('b')]))),
+        },
+        'a'.alt('a') => {
+            "" => Err((0, Error::Char('a'))),
+        },
+        digit.alt(letter) => {
+            "" => Err((0, Expected(vec!["digit", "letter"]))),
+            "π" => Ok((2, 'π')),
+        },
+    }
+}
+
+#[test]
+fn choice() {
+    t! {
+        Choice(Capture("ab".p() >> 'c'), Choice("abd", "x")) => {
+            "abd" => Ok((3, "abd")),
+            "x" => Ok((1, "x")),
+            "abx" => Err((2, Error::Char('c'))),
+            "y" => Err((0, Error::Expected(vec![Error::Str("ab"), Error::Str("abd"), Error::Str("x")]))),
+        },
+        Choice('a'.p() >> 'b', 'a'.p() >> 'c') => {
+            "ad" => Err((1, Error::Expected(vec![Error::Char('b'), Error::Char('c')]))),
+        },
+    }
+}
+
+#[test]
+fn or_without_merge() {
+    let a = |input, from| 'a'.map_err(|_| "a").parse(input, from);
+    let b = |input, from| 'b'.map_err(|_| "b").parse(input, from);
+
+    t! {
+        a.or(b) => {
+            "" => Err((0, "b")),
+            "b" => Ok((1, 'b')),
+        },
+        'a'.or('b').or("cd".map(|_| 'c')) => {
+            "" => Err((0, Error::Str("cd"))),
+            "c" => Err((0, Error::Str("cd"))),
+            "b" => Ok((1, 'b')),
+        },
+        'a'.p() | 'b' | "cd".map(|_| 'c') => {
+            "" => Err((0, Error::Str("cd"))),
+        },
+        a.p() | b => {
+            "c" => Err((0, "b")),
+        },
+    }
+}
+
+#[test]
+fn label() {
+    t! {
+        TakeWhile1(char::is_alphabetic).label("identifier") => {
+            "" => Err((0, Error::Label("identifier"))),
+            "1" => Err((0, Error::Label("identifier"))),
+            "πr" => Ok((3, "πr")),
+        },
+        ('π'.p() >> 'r').label("πr") => {
+            "" => Err((0, Error::Label("πr"))),
+            "π" => Err((2, Error::Char('r'))),
+        },
+        TakeWhile1(char::is_alphabetic).label("identifier").alt(TakeWhile1(|ch: char| ch.is_ascii_digit()).label("integer")) => {
+            "" => Err((0, Error::Expected(vec![Error::Label("identifier"), Error::Label("integer")]))),
+            "12" => Ok((2, "12")),
+        },
+    }
+}
+
+#[test]
+fn context() {
+    let key = || {
+        (
+            TakeWhile1(char::is_alphabetic).p() << '=',
+            TakeWhile(char::is_alphabetic),
+        )
+            .context("key")
+    };
+    let mut section = ('['.p() >> key() << ']').context("section");
+    let context =
+        |name: &'static str, from, error| Error::Context(name.into(), from, Box::new(error));
+
+    assert_eq!(section.parse("", 0), Err((0, Error::Char('['))));
+    assert_eq!(
+        section.parse("[", 0),
+        Err((1, context("section", 0, Error::TakeWhile1)))
+    );
+    assert_eq!(
+        section.parse("[π", 0),
+        Err((
+            3,
+            context("section", 0, context("key", 1, Error::Char('=')))
+        ))
+    );
+    assert_eq!(
+        section.parse("[π=r", 0),
+        Err((5, context("section", 0, Error::Char(']'))))
+    );
+    assert_eq!(section.parse("[π=r]", 0), Ok((6, ("π", "r"))));
+    assert_eq!(
+        section.parse("🐱[π", 4),
+        Err((
+            7,
+            context("section", 4, context("key", 5, Error::Char('=')))
+        ))
+    );
+
+    assert_eq!(
+        Error::Context(
+            "section".into(),
+            0,
+            Box::new(Error::Context("key".into(), 1, Box::new(Error::Char('='))))
+        )
+        .to_string(),
+        "in section → in key → expected '='"
+    );
+}
+
+#[test]
+fn context_owned() {
+    let mut built = 0;
+    let mut key = |name: &'static str| {
+        let mut p = (TakeWhile1(char::is_alphabetic).p() << '=')
+            .context_with(|| {
+                built += 1;
+                format!("key '{}'", name)
+            })
+            .context(format!("line {}", 1));
+        p.parse(name, 0)
+    };
+
+    assert_eq!(key("port="), Ok((5, "port")));
+    assert_eq!(
+        key("port").map_err(|(from, error)| (from, error.to_string())),
+        Err((4, "in line 1 → in key 'port' → expected '='".to_string()))
+    );
+    assert_eq!(key("").unwrap_err(), (0, Error::TakeWhile1));
+    assert_eq!(built, 1);
+}
+
 #[test]
 fn r#try() {
     t! {
@@ -201,6 +376,83 @@ fn r#try() {
     }
 }
 
+#[test]
+fn lookahead() {
+    t! {
+        Lookahead("if") => {
+            "" => Err((0, Error::Str("if"))),
+            "i" => Err((0, Error::Str("if"))),
+            "if" => Ok((0, "if")),
+            "iffy" => Ok((0, "if")),
+        },
+        (Lookahead('π'), Any) => {
+            "π" => Ok((2, ('π', 'π'))),
+            "r" => Err((0, Error::Char('π'))),
+        },
+        (Try(('π', 'r')), Lookahead('²')).map(|_| 1).or(Lookahead('π').map(|_| 2)) => {
+            "πr²" => Ok((3, 1)),
+            "πr" => Err((3, Error::Char('²'))),
+            "π" => Ok((0, 2)),
+        },
+        Lookahead(('π', 'r')) => {
+            "πx" => Err((2, Error::Char('r'))),
+        },
+        Try(Lookahead(('π', 'r'))) => {
+            "πx" => Err((0, Error::Char('r'))),
+        },
+        Lookahead('π'.map_err(|_| "π")) => {
+            "π" => Ok((0, 'π')),
+            "r" => Err((0, "π")),
+        },
+    }
+}
+
+#[test]
+fn not() {
+    let ident_char = || Satisfy(|ch: char| ch.is_alphanumeric() || ch == '_');
+    t! {
+        Not('π') => {
+            "" => Ok((0, ())),
+            "r" => Ok((0, ())),
+            "π" => Err((0, Error::Not)),
+        },
+        Try(("if", Not(ident_char())))
+            .map(|(str, _)| str)
+            .alt(TakeWhile1(|ch: char| ch.is_alphanumeric() || ch == '_')) => {
+            "if" => Ok((2, "if")),
+            "if x" => Ok((2, "if")),
+            "iffy" => Ok((4, "iffy")),
+            "" => Err((0, Error::Expected(vec![Error::Str("if"), Error::TakeWhile1]))),
+        },
+        Not("//").skip_and(Any).repeat(..).collect::<String, _>() => {
+            "ab//c" => Ok((2, "ab".into())),
+            "//" => Ok((0, "".into())),
+        },
+        (Not('.'), Any).optional() => {
+            "." => Ok((0, None)),
+            "a" => Ok((1, Some(((), 'a')))),
+        },
+    }
+    assert_eq!(Error::Not.to_string(), "unexpected input");
+}
+
+#[test]
+fn not_with() {
+    let keyword = |input, from| {
+        "if".p()
+            .or("else")
+            .map_err(|_| "keyword")
+            .parse(input, from)
+    };
+    t! {
+        NotWith(keyword, |keyword: &str| if keyword == "if" { "if" } else { "else" }) => {
+            "if" => Err((0, "if")),
+            "else" => Err((0, "else")),
+            "x" => Ok((0, ())),
+        },
+    }
+}
+
 #[test]
 fn map() {
     t! {
@@ -301,7 +553,7 @@ fn and_then() {
     }
 
     t! {
-        TakeWhile1(|ch| '0' <= ch && ch <= '9')
+        TakeWhile1(|ch: char| ch.is_ascii_digit())
             .map_err(Error::Munch)
             .and_then(|str: &str| str.parse::<u8>().map_err(Error::ParseIntError)) => {
             "" => Err((0, Error::Munch(munch::error::Error::TakeWhile1))),
@@ -369,7 +621,7 @@ fn guard() {
     }
 
     let mut parser = muncher! {
-        str <- Capture((Optional('-'), TakeWhile1(|ch| ch.is_digit(10))))
+        str <- Capture((Optional('-'), TakeWhile1(|ch| ch.is_ascii_digit())))
             .map_err(|_| Error::NotANumber),
         let n = str.parse().unwrap(),
         Guard(|| n <= 255, || Error::Huge),
@@ -519,8 +771,43 @@ fn fold() {
     }
 }
 
+#[test]
+fn iter() {
+    let mut line = TakeWhile1(|ch| ch != '\n' && ch != '=').p() << '\n';
+
+    let mut iter = line.by_ref().iter("πr\nr²\n");
+    assert_eq!(iter.next(), Some(Ok("πr")));
+    assert_eq!(iter.offset(), 4);
+    assert_eq!(iter.next(), Some(Ok("r²")));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.offset(), 8);
+
+    let mut iter = line.by_ref().iter("πr\n=\n");
+    assert_eq!(
+        iter.by_ref().collect::<Vec<_>>(),
+        [Ok("πr"), Err(IterError::Parse(4, Error::TakeWhile1))]
+    );
+    assert_eq!(iter.offset(), 4);
+
+    let mut iter = line.by_ref().iter("πr\nr²");
+    assert_eq!(iter.next(), Some(Ok("πr")));
+    assert_eq!(
+        iter.next(),
+        Some(Err(IterError::Parse(7, Error::Char('\n'))))
+    );
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.offset(), 4);
+
+    // A parser that succeeds without consuming anything would never finish.
+    let mut iter = TakeWhile(|ch| ch != '\n').iter("πr\n");
+    assert_eq!(iter.next(), Some(Ok("πr")));
+    assert_eq!(iter.next(), Some(Err(IterError::Stalled(3))));
+    assert_eq!(iter.next(), None);
+}
+
 #[test]
 fn join() {
+    #[allow(clippy::manual_div_ceil)]
     fn t<R: Range>(range: R) {
         use std::cmp::Ordering::*;
         let (min, max) = (range.min(), range.max());
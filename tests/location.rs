@@ -0,0 +1,52 @@
+use munch::error::Error;
+use munch::location::*;
+use munch::str::*;
+use munch::Parser;
+
+fn loc(line: usize, column: usize, utf16_column: usize, offset: usize) -> Location {
+    Location {
+        offset,
+        line,
+        column,
+        utf16_column,
+    }
+}
+
+#[test]
+fn location() {
+    let index = LineIndex::new("πr²\n\nab🐱c\r\nd");
+    assert_eq!(index.lines(), 4);
+    assert_eq!(index.location(0), loc(0, 0, 0, 0));
+    assert_eq!(index.location(2), loc(0, 1, 1, 2));
+    assert_eq!(index.location(5), loc(0, 3, 3, 5));
+    assert_eq!(index.location(6), loc(1, 0, 0, 6));
+    assert_eq!(index.location(7), loc(2, 0, 0, 7));
+    assert_eq!(index.location(9), loc(2, 2, 2, 9));
+    assert_eq!(index.location(13), loc(2, 3, 4, 13));
+    assert_eq!(index.location(14), loc(2, 4, 5, 14));
+    assert_eq!(index.location(16), loc(3, 0, 0, 16));
+    assert_eq!(index.location(17), loc(3, 1, 1, 17));
+    assert_eq!(index.location(100), loc(3, 1, 1, 17));
+    assert_eq!(index.location(9).to_string(), "3:3");
+}
+
+#[test]
+fn line() {
+    let index = LineIndex::new(b"a\n\nbc\r\nd".as_ref());
+    assert_eq!(index.line(0), Some(b"a".as_ref()));
+    assert_eq!(index.line(1), Some(b"".as_ref()));
+    assert_eq!(index.line(2), Some(b"bc".as_ref()));
+    assert_eq!(index.line(3), Some(b"d".as_ref()));
+    assert_eq!(index.line(4), None);
+}
+
+#[test]
+fn locate() {
+    let input = "[owner]\nname John";
+    let index = LineIndex::new(input);
+    let result = (TakeWhile1(char::is_alphabetic).p() << '=').parse(input, 8);
+    assert_eq!(
+        result.map_err(|error| index.locate(error)),
+        Err((loc(1, 4, 4, 12), Error::Char('=')))
+    );
+}
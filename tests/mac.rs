@@ -13,7 +13,7 @@ fn mac() {
         AllEqual(u8),
     }
 
-    let mut u8 = TakeWhile1(|ch| '0' <= ch && ch <= '9')
+    let mut u8 = TakeWhile1(|ch: char| ch.is_ascii_digit())
         .map_err(Error::Munch)
         .and_then(|str: &str| str.parse::<u8>().map_err(Error::ParseInt));
 
@@ -72,7 +72,7 @@ fn mac() {
             'n' => "null",
             'f' => "false",
             't' => "true",
-            '0' | '1' | '2' ..= '9' => TakeWhile1(|ch| ch.is_digit(10)),
+            '0' | '1' | '2' ..= '9' => TakeWhile1(|ch| ch.is_ascii_digit()),
             _ => |_, from| Err((from, munch::error::Error::Satisfy)),
         },
         End,
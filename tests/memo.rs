@@ -0,0 +1,138 @@
+use std::cell::Cell;
+
+use munch::error::Error;
+use munch::memo::{LeftRec, Memo};
+use munch::str::*;
+use munch::{Parser, Try};
+
+#[test]
+fn memoize() {
+    let calls = Cell::new(0);
+    let word = |str, from| {
+        calls.set(calls.get() + 1);
+        TakeWhile1(char::is_alphabetic).parse(str, from)
+    };
+
+    let memo = Memo::new();
+    let mut parser = Try(word.memoize(&memo) << '!').p()
+        | Try(word.memoize(&memo) << '?')
+        | word.memoize(&memo) << '.';
+
+    assert_eq!(parser.parse("πr.", 0), Ok((4, "πr")));
+    assert_eq!(calls.get(), 1);
+    assert_eq!(memo.len(), 1);
+
+    assert_eq!(parser.parse("πr;", 0), Err((3, Error::Char('.'))));
+    assert_eq!(calls.get(), 1);
+
+    memo.clear();
+    assert!(memo.is_empty());
+    assert_eq!(parser.parse("1", 0), Err((0, Error::TakeWhile1)));
+    assert_eq!(calls.get(), 2);
+    assert_eq!(parser.parse("1", 0), Err((0, Error::TakeWhile1)));
+    assert_eq!(calls.get(), 2);
+}
+
+type Result = munch::Result<i64, Error<'static>>;
+
+fn number(str: &str, from: usize) -> Result {
+    TakeWhile1(|ch: char| ch.is_ascii_digit())
+        .map(|str: &str| str.parse().unwrap())
+        .label("number")
+        .parse(str, from)
+}
+
+// expr = expr ('-' | '/') number | number
+fn expr(str: &str, from: usize, table: &LeftRec<i64, Error<'static>>) -> Result {
+    let expr = |str, from| expr(str, from, table);
+    let op = '-'.or('/');
+    ((expr, op, number).map(|(a, op, b)| if op == '-' { a - b } else { a / b }) | number)
+        .grow(table)
+        .parse(str, from)
+}
+
+// sub = diff | number; diff = sub '-' number
+fn sub(str: &str, from: usize, table: &LeftRec<i64, Error<'static>>) -> Result {
+    let diff = |str, from| {
+        (|str, from| sub(str, from, table))
+            .p()
+            .and_skip('-')
+            .and(number)
+            .map(|(a, b)| a - b)
+            .parse(str, from)
+    };
+    (diff.p() | number).grow(table).parse(str, from)
+}
+
+#[test]
+fn grow() {
+    let table = LeftRec::new();
+    let parse = |str, rule: fn(&str, usize, &LeftRec<i64, Error<'static>>) -> Result| {
+        table.clear();
+        rule(str, 0, &table)
+    };
+
+    assert_eq!(parse("", expr), Err((0, Error::Label("number"))));
+    assert_eq!(parse("7", expr), Ok((1, 7)));
+    assert_eq!(parse("10-3-2", expr), Ok((6, 5)));
+    assert_eq!(parse("100/5/2-3", expr), Ok((9, 7)));
+    assert_eq!(parse("10-3-", expr), Ok((4, 7)));
+
+    assert_eq!(parse("", sub), Err((0, Error::Label("number"))));
+    assert_eq!(parse("1-2-3", sub), Ok((5, -4)));
+    assert_eq!(parse("1-2-3-", sub), Ok((5, -4)));
+}
+
+type Table = LeftRec<i64, Error<'static>>;
+
+// sum = term '+' number | number; term = sum
+fn sum(str: &str, from: usize, tables: &(Table, Table)) -> Result {
+    let term = |str, from| term(str, from, tables);
+    ((term, '+', number).map(|(a, _, b)| a + b) | number)
+        .grow(&tables.0)
+        .parse(str, from)
+}
+
+fn term(str: &str, from: usize, tables: &(Table, Table)) -> Result {
+    (|str, from| sum(str, from, tables))
+        .p()
+        .grow(&tables.1)
+        .parse(str, from)
+}
+
+// x = y '*' number | number; y = x '-' number | x
+fn x(str: &str, from: usize, tables: &(Table, Table)) -> Result {
+    let y = |str, from| y(str, from, tables);
+    (Try((y, '*', number)).map(|(a, _, b)| a * b) | number)
+        .grow(&tables.0)
+        .parse(str, from)
+}
+
+fn y(str: &str, from: usize, tables: &(Table, Table)) -> Result {
+    let x = |str, from| x(str, from, tables);
+    (Try((x, '-', number)).map(|(a, _, b)| a - b) | x)
+        .grow(&tables.1)
+        .parse(str, from)
+}
+
+#[test]
+fn grow_indirect() {
+    let tables = (LeftRec::new(), LeftRec::new());
+    let parse = |str, rule: fn(&str, usize, &(Table, Table)) -> Result| {
+        tables.0.clear();
+        tables.1.clear();
+        rule(str, 0, &tables)
+    };
+
+    assert_eq!(parse("", sum), Err((0, Error::Label("number"))));
+    assert_eq!(parse("1", sum), Ok((1, 1)));
+    assert_eq!(parse("1+2+3", sum), Ok((5, 6)));
+    assert_eq!(parse("1+2+3", term), Ok((5, 6)));
+    assert_eq!(parse("1+2+", sum), Ok((3, 3)));
+
+    // ((((2 * 3) - 1) * 4) - 5) = 15
+    assert_eq!(parse("2*3-1*4-5", y), Ok((9, 15)));
+    assert_eq!(parse("2*3-1*4", x), Ok((7, 20)));
+    assert_eq!(parse("2-1", x), Ok((1, 2)));
+    assert_eq!(parse("2-1", y), Ok((3, 1)));
+}
@@ -0,0 +1,142 @@
+use munch::error::{Error, Number};
+use munch::num::{self, Float, Integer, Radix, Syntax};
+use munch::{Parse, Parser};
+
+#[macro_use]
+mod t;
+
+#[test]
+fn integer() {
+    t_and_tb! {
+        num::u8() => {
+            "" => Err((0, Error::Number(Number::Integer))),
+            "x" => Err((0, Error::Number(Number::Integer))),
+            "-1" => Err((0, Error::Number(Number::Integer))),
+            "0" => Ok((1, 0)),
+            "007x" => Ok((3, 7)),
+            "255" => Ok((3, 255)),
+            "256" => Err((3, Error::Number(Number::Overflow("u8")))),
+            "1_0" => Ok((1, 1)),
+        },
+        num::i8() => {
+            "-" => Err((0, Error::Number(Number::Integer))),
+            "-0" => Ok((2, 0)),
+            "127" => Ok((3, 127)),
+            "128" => Err((3, Error::Number(Number::Overflow("i8")))),
+            "-128" => Ok((4, -128)),
+            "-129" => Err((4, Error::Number(Number::Overflow("i8")))),
+            "+1" => Err((0, Error::Number(Number::Integer))),
+        },
+        num::u64() => {
+            "18446744073709551615" => Ok((20, u64::MAX)),
+            "18446744073709551616" => Err((20, Error::Number(Number::Overflow("u64")))),
+        },
+        num::i128() => {
+            "-170141183460469231731687303715884105728" => Ok((40, i128::MIN)),
+        },
+    }
+}
+
+#[test]
+fn integer_radix() {
+    t_and_tb! {
+        num::u32().radix(Radix::Hexadecimal) => {
+            "fF" => Ok((2, 255)),
+            "0x1" => Ok((1, 0)),
+            "g" => Err((0, Error::Number(Number::Integer))),
+        },
+        num::u8().radix(Radix::Binary) => {
+            "1012" => Ok((3, 5)),
+            "111111111" => Err((9, Error::Number(Number::Overflow("u8")))),
+        },
+        num::i16().prefixed() => {
+            "12" => Ok((2, 12)),
+            "0" => Ok((1, 0)),
+            "0x7fff" => Ok((6, 0x7fff)),
+            "-0x8000" => Ok((7, -0x8000)),
+            "0X1F" => Ok((4, 0x1f)),
+            "0o17" => Ok((4, 0o17)),
+            "0b101" => Ok((5, 0b101)),
+            "0b102" => Ok((4, 0b10)),
+            "0x" => Err((2, Error::Number(Number::Digit(Radix::Hexadecimal)))),
+            "-0o8" => Err((3, Error::Number(Number::Digit(Radix::Octal)))),
+            "0b_1" => Err((2, Error::Number(Number::Digit(Radix::Binary)))),
+        },
+        Integer::<u32>::new().prefixed().underscores() => {
+            "_1" => Err((0, Error::Number(Number::Integer))),
+            "1_000_" => Ok((6, 1000)),
+            "0x_ff_ff" => Ok((8, 0xffff)),
+            "0b__" => Err((4, Error::Number(Number::Digit(Radix::Binary)))),
+        },
+    }
+}
+
+#[test]
+fn float() {
+    t_and_tb! {
+        num::f64() => {
+            "" => Err((0, Error::Number(Number::Float))),
+            "-" => Err((0, Error::Number(Number::Float))),
+            "0" => Ok((1, 0.0)),
+            "-0.5" => Ok((4, -0.5)),
+            "012" => Err((0, Error::Number(Number::Float))),
+            "-01" => Err((0, Error::Number(Number::Float))),
+            "0.1" => Ok((3, 0.1)),
+            "1.5e3" => Ok((5, 1500.0)),
+            "1E+2" => Ok((4, 100.0)),
+            "2e-1" => Ok((4, 0.2)),
+            "1." => Ok((1, 1.0)),
+            ".5" => Err((0, Error::Number(Number::Float))),
+            "1e" => Ok((1, 1.0)),
+            "1e+x" => Ok((1, 1.0)),
+            "1_0" => Ok((1, 1.0)),
+            "1e400" => Err((5, Error::Number(Number::Overflow("f64")))),
+        },
+        num::f32() => {
+            "3.25" => Ok((4, 3.25)),
+            "1e39" => Err((4, Error::Number(Number::Overflow("f32")))),
+        },
+        num::f64().syntax(Syntax::Rust) => {
+            "012" => Ok((3, 12.0)),
+            "1_000.000_1" => Ok((11, 1000.0001)),
+            "1." => Ok((2, 1.0)),
+            "1.." => Ok((1, 1.0)),
+            "1.e" => Ok((1, 1.0)),
+            "1._0" => Ok((1, 1.0)),
+            "1e_1_" => Ok((5, 10.0)),
+            "1e_" => Ok((1, 1.0)),
+            "_1" => Err((0, Error::Number(Number::Float))),
+            ".5" => Err((0, Error::Number(Number::Float))),
+        },
+        Float::<f64>::new().syntax(Syntax::C) => {
+            ".5" => Ok((2, 0.5)),
+            "-.5e1" => Ok((5, -5.0)),
+            "1." => Ok((2, 1.0)),
+            "1.x" => Ok((2, 1.0)),
+            "." => Err((0, Error::Number(Number::Float))),
+            "1_0" => Ok((1, 1.0)),
+        },
+    }
+}
+
+#[test]
+fn parse() {
+    assert_eq!(u16::parse("65535", 0), Ok((5, 65535)));
+    assert_eq!(i32::parse(b"-12,".as_ref(), 0), Ok((3, -12)));
+    assert_eq!(f64::parse("x2.5", 1), Ok((4, 2.5)));
+
+    let mut p = (num::u8(), ',', num::f32()).map(|(a, _, b)| (a, b));
+    assert_eq!(p.parse("1,2", 0), Ok((3, (1, 2.0))));
+    assert_eq!(
+        p.parse("300,2", 0)
+            .map_err(|(from, error)| (from, error.to_string())),
+        Err((3, "expected a number that fits in u8".to_string()))
+    );
+    assert_eq!(
+        num::i8()
+            .prefixed()
+            .parse("0x", 0)
+            .map_err(|(from, error)| (from, error.to_string())),
+        Err((2, "expected a hexadecimal digit".to_string()))
+    );
+}
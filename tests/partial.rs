@@ -0,0 +1,199 @@
+use std::io::{self, Read};
+
+use munch::error::{Ascii, BinaryType, Endianness, Error, Needed};
+use munch::partial::*;
+use munch::Parser;
+
+fn incomplete(needed: usize) -> Error<'static> {
+    Error::Incomplete(Needed::Size(needed))
+}
+
+const MORE: Error<'static> = Error::Incomplete(Needed::Unknown);
+
+#[test]
+fn byte() {
+    use munch::partial::byte::*;
+
+    let p = |input: &'static [u8]| Partial::new(input);
+    let c = |input: &'static [u8]| Partial::complete(input);
+
+    assert_eq!(Byte(b'a').parse(p(b"ab"), 1), Err((1, Error::Byte(b'a'))));
+    assert_eq!(Byte(b'a').parse(p(b"ab"), 2), Err((3, incomplete(1))));
+    assert_eq!(Byte(b'a').parse(c(b"ab"), 2), Err((2, Error::Byte(b'a'))));
+
+    assert_eq!(
+        Bytes(b"GET").parse(p(b"GET /"), 0),
+        Ok((3, b"GET".as_ref()))
+    );
+    assert_eq!(Bytes(b"GET").parse(p(b"GE"), 0), Err((3, incomplete(1))));
+    assert_eq!(
+        Bytes(b"GET").parse(p(b"GO"), 0),
+        Err((0, Error::Bytes(b"GET")))
+    );
+    assert_eq!(
+        Bytes(b"GET").parse(c(b"GE"), 0),
+        Err((0, Error::Bytes(b"GET")))
+    );
+
+    assert_eq!(Take(4).parse(p(b"ab"), 0), Err((4, incomplete(2))));
+    assert_eq!(Take(4).parse(c(b"ab"), 0), Err((0, Error::Take(4))));
+    assert_eq!(Take(2).parse(p(b"ab"), 0), Ok((2, b"ab".as_ref())));
+
+    let mut digits = TakeWhile1(|b: u8| b.is_ascii_digit());
+    assert_eq!(digits.parse(p(b"12;"), 0), Ok((2, b"12".as_ref())));
+    assert_eq!(digits.parse(p(b"12"), 0), Err((3, MORE)));
+    assert_eq!(digits.parse(c(b"12"), 0), Ok((2, b"12".as_ref())));
+    assert_eq!(digits.parse(p(b";"), 0), Err((0, Error::TakeWhile1)));
+
+    assert_eq!(
+        Capture((Any, Peek)).parse(p(b"ab"), 0),
+        Ok((1, b"a".as_ref()))
+    );
+    assert_eq!(
+        Capture((Any, Peek)).parse(p(b"a"), 0),
+        Err((2, incomplete(1)))
+    );
+    assert_eq!(
+        Satisfy(|b| b == b'a').parse(p(b""), 0),
+        Err((1, incomplete(1)))
+    );
+    assert_eq!(End.parse(p(b""), 0), Err((1, MORE)));
+    assert_eq!(End.parse(c(b""), 0), Ok((0, ())));
+
+    assert_eq!(BigEndian::u32(p(b"\x01\x02"), 0), Err((4, incomplete(2))));
+    assert_eq!(BigEndian::u16(p(b"\x01\x02"), 0), Ok((2, 0x0102)));
+    assert_eq!(
+        LittleEndian::u32(c(b"\x01\x02"), 0),
+        Err((0, Error::Binary(Endianness::Little, BinaryType::u32)))
+    );
+}
+
+#[test]
+fn str() {
+    use munch::partial::str::*;
+
+    let p = |input: &'static str| Partial::new(input);
+    let c = |input: &'static str| Partial::complete(input);
+
+    assert_eq!(Char('π').parse(p("π"), 0), Ok((2, 'π')));
+    assert_eq!(Char('π').parse(p("π"), 2), Err((4, incomplete(2))));
+    assert_eq!(Char('π').parse(c("π"), 2), Err((2, Error::Char('π'))));
+    assert_eq!(Str("πr²").parse(p("πr"), 0), Err((5, incomplete(2))));
+    assert_eq!(Str("πr²").parse(p("πx"), 0), Err((0, Error::Str("πr²"))));
+
+    let mut word = TakeWhile(char::is_alphabetic);
+    assert_eq!(word.parse(p("πr 2"), 0), Ok((3, "πr")));
+    assert_eq!(word.parse(p("πr"), 0), Err((4, MORE)));
+    assert_eq!(word.parse(c("πr"), 0), Ok((3, "πr")));
+
+    assert_eq!(Any.parse(p("π"), 2), Err((3, incomplete(1))));
+    assert_eq!(Peek.parse(c("π"), 0), Ok((0, 'π')));
+}
+
+#[test]
+fn ascii() {
+    use munch::partial::ascii::*;
+
+    let mut digits = TakeWhile1(|b: u8| b.is_ascii_digit());
+    assert_eq!(digits.parse(Partial::new("12π"), 0), Ok((2, "12")));
+    assert_eq!(digits.parse(Partial::new("12"), 0), Err((3, MORE)));
+    assert_eq!(
+        digits.parse(Partial::complete("π"), 0),
+        Err((0, Error::Ascii(Ascii::TakeWhile1)))
+    );
+    assert_eq!(
+        Satisfy(|b| b == b'a').parse(Partial::new(b"".as_ref()), 0),
+        Err((1, incomplete(1)))
+    );
+}
+
+#[test]
+fn combinators() {
+    use munch::partial::byte::*;
+
+    let p = |input: &'static [u8]| Partial::new(input);
+    let c = |input: &'static [u8]| Partial::complete(input);
+
+    // An incomplete alternative must not fall through to the next one.
+    let mut method = Bytes(b"GET").p() | Bytes(b"GETS");
+    assert_eq!(method.parse(p(b"GE"), 0), Err((3, incomplete(1))));
+
+    let mut a = Byte(b'a').repeat(..).collect();
+    assert_eq!(a.parse(p(b"aab"), 0), Ok((2, vec![b'a', b'a'])));
+    assert_eq!(a.parse(p(b"aa"), 0), Err((3, incomplete(1))));
+    assert_eq!(a.parse(c(b"aa"), 0), Ok((2, vec![b'a', b'a'])));
+
+    let mut optional = (Byte(b'a'), Byte(b'b').optional());
+    assert_eq!(optional.parse(p(b"a"), 0), Err((2, incomplete(1))));
+    assert_eq!(optional.parse(c(b"a"), 0), Ok((1, (b'a', None))));
+
+    // Lookahead must not hide a need for more input either.
+    let mut not = munch::Not(Bytes(b"//"));
+    assert_eq!(not.parse(p(b"/"), 0), Err((2, incomplete(1))));
+    assert_eq!(not.parse(c(b"/"), 0), Ok((0, ())));
+    let mut lookahead = munch::Lookahead(Bytes(b"//"));
+    assert_eq!(lookahead.parse(p(b"/"), 0), Err((2, incomplete(1))));
+    assert_eq!(lookahead.parse(p(b"//"), 0), Ok((0, b"//".as_ref())));
+
+    // Neither may backtracking.
+    let mut ab = Try((Byte(b'a'), Byte(b'b'))).optional();
+    assert_eq!(ab.parse(p(b"a"), 0), Err((2, incomplete(1))));
+    assert_eq!(ab.parse(p(b"ac"), 0), Ok((0, None)));
+    assert_eq!(ab.parse(c(b"a"), 0), Ok((0, None)));
+
+    assert_eq!(incomplete(2).to_string(), "expected 2 more bytes");
+    assert_eq!(incomplete(1).to_string(), "expected 1 more byte");
+    assert_eq!(MORE.to_string(), "expected more input");
+}
+
+// Hands out at most `size` bytes per read, like a slow socket.
+struct Chunks<'a> {
+    input: &'a [u8],
+    size: usize,
+}
+
+impl<'a> Read for Chunks<'a> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.size.min(buf.len()).min(self.input.len());
+        buf[..n].copy_from_slice(&self.input[..n]);
+        self.input = &self.input[n..];
+        Ok(n)
+    }
+}
+
+#[test]
+fn reader() {
+    use munch::partial::byte::*;
+
+    // A length-prefixed frame.
+    let mut frame = BigEndian::u16
+        .bind(|len| Take(len as usize))
+        .map(<[u8]>::to_vec);
+
+    let input = b"\x00\x03abc\x00\x00\x00\x05hello\x00\x02";
+    for size in 1..=input.len() {
+        let mut reader = Reader::with_capacity(size, Chunks { input, size });
+        assert_eq!(reader.parse(&mut frame).unwrap(), Some(b"abc".to_vec()));
+        assert_eq!(reader.parse(&mut frame).unwrap(), Some(b"".to_vec()));
+        assert_eq!(reader.parse(&mut frame).unwrap(), Some(b"hello".to_vec()));
+        assert_eq!(reader.offset(), 14);
+        match reader.parse(&mut frame) {
+            Err(ReadError::Parse(16, Error::Take(2))) => {}
+            otherwise => panic!("{:?}", otherwise),
+        }
+    }
+
+    // Lines, where the last one isn't terminated.
+    let mut line = TakeWhile(|b| b != b'\n')
+        .map(<[u8]>::to_vec)
+        .and_skip(Byte(b'\n').optional());
+
+    let input = b"first\n\nthird";
+    let mut reader = Reader::with_capacity(2, Chunks { input, size: 3 });
+    let mut lines = vec![];
+    while let Some(line) = reader.parse(&mut line).unwrap() {
+        lines.push(line);
+    }
+    assert_eq!(lines, [b"first".as_ref(), b"", b"third"]);
+    assert_eq!(reader.buffer(), b"");
+}
@@ -0,0 +1,68 @@
+use munch::class::Class;
+use munch::error::Error;
+
+munch_macros::peg! {
+    pub expr -> i64 = l:term "+" r:expr { l + r } / l:term "-" r:expr { l - r } / term;
+    term -> i64 = l:atom "*" r:term { l * r } / atom;
+    atom -> i64 = ws n:number ws { n } / ws "(" e:expr ")" ws { e };
+    number -> i64 = n:$(['0'..='9']+) { n.parse().unwrap() };
+    ws = [" \t"]*;
+}
+
+#[test]
+fn arithmetic() {
+    assert_eq!(expr("1", 0), Ok((1, 1)));
+    assert_eq!(expr("1 + 2 * 3", 0), Ok((9, 7)));
+    assert_eq!(expr("(1 + 2) * 3", 0), Ok((11, 9)));
+    assert_eq!(expr(" 10 - 2 - 3 ", 0), Ok((12, 11)));
+    assert_eq!(expr("1 +", 0), Ok((2, 1)));
+    let digit = Class::new().range('0'..='9');
+    assert_eq!(
+        expr("x", 0),
+        Err((
+            0,
+            Error::Expected(vec![Error::OneOf(digit), Error::Str("(")])
+        ))
+    );
+    // The error is reported where the furthest alternative failed, not where the choice started.
+    assert_eq!(expr("(1 + 2", 0), Err((6, Error::Str(")"))));
+}
+
+munch_macros::peg! {
+    keyword = ("if" / "else") !ident_char;
+    ident_char = ["a-zA-Z0-9_"];
+    pub ident -> &'input str = !keyword s:$(["a-zA-Z_"] ident_char*) { s };
+    pub word -> (&'input str, Option<char>) = w:$(ident_char+) &"!" b:'!'? { (w, b) };
+    pub not_digit -> char = c:[^ '0'..='9'] { c };
+    pub anything -> Vec<char> = .*;
+}
+
+munch_macros::peg! {
+    pub range -> (i64, i64) = from:number ".." to:number { (from, to) };
+    pub quoted -> &'input str = "'" input:$([^ '\'']*) "'" { input };
+}
+
+#[test]
+fn labels() {
+    assert_eq!(range("1..23", 0), Ok((5, (1, 23))));
+    assert_eq!(quoted("'ab' c", 0), Ok((4, "ab")));
+}
+
+#[test]
+fn lookahead() {
+    assert_eq!(ident("iffy", 0), Ok((4, "iffy")));
+    assert_eq!(ident("_if", 0), Ok((3, "_if")));
+    assert_eq!(ident("if", 0), Err((0, Error::Not)));
+    assert_eq!(ident("else x", 0), Err((0, Error::Not)));
+
+    assert_eq!(word("hey!", 0), Ok((4, ("hey", Some('!')))));
+    assert_eq!(word("hey", 0), Err((3, Error::Str("!"))));
+
+    assert_eq!(not_digit("a", 0), Ok((1, 'a')));
+    assert_eq!(
+        not_digit("1", 0),
+        Err((0, Error::NoneOf(Class::new().range('0'..='9'))))
+    );
+
+    assert_eq!(anything("ab", 0), Ok((2, vec!['a', 'b'])));
+}
@@ -0,0 +1,113 @@
+use std::rc::Rc;
+
+use munch::error::Error;
+use munch::pratt::{Assoc, Pratt};
+use munch::str::*;
+use munch::Parser;
+
+fn expr(str: &str, from: usize) -> munch::Result<String, Error<'static>> {
+    let ws = TakeWhile(char::is_whitespace);
+
+    let atom = |str, from| {
+        (TakeWhile1(char::is_alphanumeric)
+            .map(String::from)
+            .label("atom")
+            .alt('('.p() >> ws >> expr << ')'))
+        .parse(str, from)
+    };
+    let op = |op: char| op.p() << ws;
+
+    Pratt::new(atom.p() << ws)
+        .prefix(op('-'), 5, |op, x| format!("({} {})", op, x))
+        .prefix(op('+'), 5, |op, x| format!("({} {})", op, x))
+        .postfix(op('!'), 6, |x, op| format!("({} {})", op, x))
+        .postfix('['.p() >> ws >> expr << ']' << ws, 6, |x, index| {
+            format!("([] {} {})", x, index)
+        })
+        .infix(op('+').or(op('-')), Assoc::Left, 2, |a, op, b| {
+            format!("({} {} {})", op, a, b)
+        })
+        .infix(op('*').or(op('/')), Assoc::Left, 3, |a, op, b| {
+            format!("({} {} {})", op, a, b)
+        })
+        .infix(op('^'), Assoc::Right, 4, |a, op, b| {
+            format!("({} {} {})", op, a, b)
+        })
+        .infix(op('=').map(|_| '='), Assoc::Right, 0, |a, _, b| {
+            format!("(= {} {})", a, b)
+        })
+        .infix(
+            op('?').p() >> expr << ':' << ws,
+            Assoc::Right,
+            1,
+            |cond, then, otherwise| format!("(? {} {} {})", cond, then, otherwise),
+        )
+        .parse(str, from)
+}
+
+fn parse(str: &str) -> Result<String, (usize, Error<'static>)> {
+    (expr.p() << End).parse(str, 0).map(|(_, output)| output)
+}
+
+#[test]
+fn pratt() {
+    assert_eq!(parse("1"), Ok("1".into()));
+    assert_eq!(parse("1 + 2 * 3"), Ok("(+ 1 (* 2 3))".into()));
+    assert_eq!(parse("1 - 2 - 3"), Ok("(- (- 1 2) 3)".into()));
+    assert_eq!(parse("1 * 2 + 3 / 4"), Ok("(+ (* 1 2) (/ 3 4))".into()));
+    assert_eq!(parse("2 ^ 3 ^ 4"), Ok("(^ 2 (^ 3 4))".into()));
+    assert_eq!(parse("--1 * 2"), Ok("(* (- (- 1)) 2)".into()));
+    assert_eq!(parse("-1 ^ 2"), Ok("(^ (- 1) 2)".into()));
+    assert_eq!(parse("1 ^ -2 * 3"), Ok("(* (^ 1 (- 2)) 3)".into()));
+    assert_eq!(parse("-1!"), Ok("(- (! 1))".into()));
+    assert_eq!(
+        parse("a[1 + 2]! * 3"),
+        Ok("(* (! ([] a (+ 1 2))) 3)".into())
+    );
+    assert_eq!(parse("(1 + 2) * 3"), Ok("(* (+ 1 2) 3)".into()));
+    assert_eq!(parse("a ? b : c ? d : e"), Ok("(? a b (? c d e))".into()));
+    assert_eq!(
+        parse("a = b = c ? d + 1 : e"),
+        Ok("(= a (= b (? c (+ d 1) e)))".into())
+    );
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        parse(""),
+        Err((
+            0,
+            Error::Expected(vec![
+                Error::Char('-'),
+                Error::Char('+'),
+                Error::Label("atom"),
+                Error::Char('(')
+            ])
+        ))
+    );
+    assert_eq!(parse("1 +"), Err((3, parse("").unwrap_err().1)));
+    assert_eq!(parse("1 2"), Err((2, Error::End)));
+    assert_eq!(parse("a[1"), Err((3, Error::Char(']'))));
+}
+
+#[test]
+fn reuse() {
+    // Operator outputs of a failed parse must not pile up across parses.
+    let rc = Rc::new(());
+    let mut expr = Pratt::new(TakeWhile1(|ch: char| ch.is_ascii_digit()).map(|_| 1))
+        .prefix('-'.p().map(|_| rc.clone()), 2, |_, x: i32| -x)
+        .infix('+'.p().map(|_| rc.clone()), Assoc::Left, 1, |a, _, b| a + b);
+    for _ in 0..3 {
+        assert_eq!(
+            expr.parse("1+-", 0),
+            Err((
+                3,
+                Error::Expected(vec![Error::Char('-'), Error::TakeWhile1])
+            ))
+        );
+    }
+    assert_eq!(Rc::strong_count(&rc), 1);
+    assert_eq!(expr.parse("1+-1", 0), Ok((4, 0)));
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
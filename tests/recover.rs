@@ -0,0 +1,115 @@
+use munch::error::Error;
+use munch::recover::Errors;
+use munch::str::*;
+use munch::{Parser, Try};
+
+#[derive(Debug, PartialEq)]
+enum Value<'a> {
+    Integer(i64),
+    Symbol(&'a str),
+    List(Vec<Value<'a>>),
+    Invalid,
+}
+
+fn value<'a>(
+    str: &'a str,
+    from: usize,
+    errors: &Errors<Error<'static>>,
+) -> munch::Result<Value<'a>, Error<'static>> {
+    let ws = TakeWhile(char::is_whitespace);
+
+    let integer = TakeWhile1(|ch: char| ch.is_ascii_digit())
+        .map(|str: &str| Value::Integer(str.parse().unwrap()))
+        .label("integer");
+    let symbol = TakeWhile1(char::is_alphabetic)
+        .map(Value::Symbol)
+        .label("symbol");
+    let list = '('.p()
+        >> ws
+        >> (|str, from| value(str, from, errors))
+            .repeat(..)
+            .map(Value::List)
+        << ')'.missing(errors, || ')');
+
+    let sync = SkipUntil(Satisfy(|ch| ch.is_whitespace() || ch == '(' || ch == ')'));
+
+    (integer.alt(symbol).alt(list) << ws)
+        .recover(errors, sync.p() << ws, |_| Value::Invalid)
+        .parse(str, from)
+}
+
+fn parse(str: &str) -> (Option<Vec<Value<'_>>>, Vec<(usize, Error<'static>)>) {
+    let errors = Errors::new();
+    let values = (TakeWhile(char::is_whitespace).p()
+        >> (|str, from| value(str, from, &errors)).repeat(..)
+        << End)
+        .parse(str, 0)
+        .ok()
+        .map(|(_, values)| values);
+    (values, errors.into_inner())
+}
+
+#[test]
+fn recover() {
+    use Value::*;
+
+    assert_eq!(
+        parse("(a 1)"),
+        (Some(vec![List(vec![Symbol("a"), Integer(1)])]), vec![])
+    );
+
+    let expected = || {
+        Error::Expected(vec![
+            Error::Label("integer"),
+            Error::Label("symbol"),
+            Error::Char('('),
+        ])
+    };
+
+    assert_eq!(
+        parse("(a ~ 1) ?! (b"),
+        (
+            Some(vec![
+                List(vec![Symbol("a"), Invalid, Integer(1)]),
+                Invalid,
+                List(vec![Symbol("b")])
+            ]),
+            vec![(3, expected()), (8, expected()), (13, Error::Char(')'))]
+        )
+    );
+
+    assert_eq!(parse(")"), (None, vec![]));
+}
+
+#[test]
+fn take() {
+    let errors = Errors::new();
+    let mut parser = 'π'.missing(&errors, || 'π');
+    assert_eq!(parser.parse("r", 0), Ok((0, 'π')));
+    assert_eq!(parser.parse("π", 0), Ok((2, 'π')));
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors.take(), vec![(0, Error::Char('π'))]);
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn rewind() {
+    let errors = Errors::new();
+
+    // Without `Rewind`, the failed branch leaves its recovered error behind.
+    let mut parser = Try('x'.missing(&errors, || 'x') >> 'y').or('z');
+    assert_eq!(parser.parse("z", 0), Ok((1, 'z')));
+    assert_eq!(errors.take(), vec![(0, Error::Char('x'))]);
+
+    let mut parser = Try('x'.missing(&errors, || 'x') >> 'y').rewind(&errors) | 'z';
+    assert_eq!(parser.parse("z", 0), Ok((1, 'z')));
+    assert!(errors.is_empty());
+    assert_eq!(parser.parse("y", 0), Ok((1, 'y')));
+    assert_eq!(errors.take(), vec![(0, Error::Char('x'))]);
+
+    let checkpoint = errors.checkpoint();
+    errors.push(0, Error::Char('a'));
+    errors.push(1, Error::Char('b'));
+    errors.rewind(checkpoint);
+    assert!(errors.is_empty());
+}
@@ -0,0 +1,117 @@
+use munch::error::*;
+use munch::report::Report;
+use munch::str::*;
+use munch::Parser;
+
+#[test]
+fn display() {
+    assert_eq!(Error::Char(']').to_string(), "expected ']'");
+    assert_eq!(Error::Str("cd").to_string(), "expected \"cd\"");
+    assert_eq!(Error::Byte(b'\n').to_string(), "expected b'\\n'");
+    assert_eq!(
+        Error::Bytes(b"a\"\xFF").to_string(),
+        "expected b\"a\\\"\\xff\""
+    );
+    assert_eq!(
+        Error::Binary(Endianness::Little, BinaryType::u32).to_string(),
+        "expected a little-endian u32"
+    );
+    assert_eq!(Error::Take(1).to_string(), "expected 1 byte");
+    assert_eq!(Error::Take(4).to_string(), "expected 4 bytes");
+    assert_eq!(Error::End.to_string(), "expected end of input");
+    assert_eq!(
+        Error::Token(None, None).to_string(),
+        "expected a matching token, found end of input"
+    );
+    assert_eq!(message("oops").to_string(), "oops");
+    assert_eq!(Error::Expected(vec![]).to_string(), "unexpected input");
+    assert_eq!(Error::Label("symbol").to_string(), "expected symbol");
+    assert_eq!(
+        Error::Expected(vec![
+            Error::Label("integer"),
+            Error::Label("symbol"),
+            Error::Char('(')
+        ])
+        .to_string(),
+        "expected integer, symbol or '('"
+    );
+    assert_eq!(
+        Error::Expected(vec![Error::Char('a')]).to_string(),
+        "expected 'a'"
+    );
+    assert_eq!(
+        Error::Expected(vec![Error::Char('a'), Error::Char('b'), Error::Str("cd")]).to_string(),
+        "expected 'a', 'b' or \"cd\""
+    );
+}
+
+#[test]
+fn report() {
+    let input = "[owner]\n[data\tbase\nport=143";
+    let (from, error) = ('['.p() >> TakeWhile1(|ch| ch != ']' && ch != '\n') << ']')
+        .parse(input, 8)
+        .unwrap_err();
+    assert_eq!(
+        Report::new(input, from, &error).to_string(),
+        "\
+error: expected ']'
+ --> 2:11
+  |
+2 | [data\tbase
+  |      \t    ^"
+    );
+    assert_eq!(
+        Report::new(input, from, &error).color(true).to_string(),
+        "\
+\x1b[1;31merror\x1b[0m\x1b[1m: expected ']'\x1b[0m
+ \x1b[1;34m-->\x1b[0m 2:11
+ \x1b[1;34m |\x1b[0m
+\x1b[1;34m2 |\x1b[0m [data\tbase
+ \x1b[1;34m |\x1b[0m      \t    \x1b[1;31m^\x1b[0m"
+    );
+}
+
+#[test]
+fn report_end() {
+    let input = b"\x01\x02".as_ref();
+    let error = Error::Take(4);
+    assert_eq!(
+        Report::new(input, 2, &error).to_string(),
+        "\
+error: expected 4 bytes
+ --> 1:3
+  |
+1 | \x01\x02
+  |   ^"
+    );
+}
+
+#[test]
+fn report_invalid_utf8() {
+    let input = b"x\n\xCF\x80\x80\x80b".as_ref();
+    let error = Error::Byte(b'c');
+    assert_eq!(
+        Report::new(input, 6, &error).to_string(),
+        "\
+error: expected b'c'
+ --> 2:2
+  |
+2 | \u{3c0}\u{FFFD}\u{FFFD}b
+  |    ^"
+    );
+
+    let index = munch::location::LineIndex::new(input);
+    assert_eq!(
+        Report::with_index(&index, 6, &error).to_string(),
+        Report::new(input, 6, &error).to_string()
+    );
+    assert_eq!(
+        Report::with_index(&index, 2, &error).to_string(),
+        "\
+error: expected b'c'
+ --> 2:1
+  |
+2 | \u{3c0}\u{FFFD}\u{FFFD}b
+  | ^"
+    );
+}
@@ -0,0 +1,51 @@
+use munch::error::Error;
+use munch::span::*;
+use munch::{ascii, byte, str, Parser};
+
+#[test]
+fn span() {
+    let a = Span::new(2, 5);
+    let b = Span::new(7, 9);
+    assert_eq!(a.merge(b), Span::new(2, 9));
+    assert_eq!(b.merge(a), Span::new(2, 9));
+    assert_eq!(a.slice("let foo = 1"), "t f");
+    assert_eq!(b.slice(b"let foo = 1".as_ref()), b" =".as_ref());
+
+    let x = Spanned::new(a, 1);
+    let y = Spanned::new(b, 2);
+    assert_eq!(x.span(), a);
+    assert_eq!(x.map(|x| x * 10), Spanned::new(a, 10));
+    assert_eq!(x.merge(y, |x, y| x + y), Spanned::new(Span::new(2, 9), 3));
+}
+
+#[test]
+fn with_span() {
+    let mut p = str::TakeWhile1(|c: char| c.is_alphabetic()).with_span();
+    assert_eq!(p.parse("🐱ab cd", 4), Ok((6, (Span::new(4, 6), "ab"))));
+    assert_eq!(p.parse("🐱ab cd", 6), Err((6, Error::TakeWhile1)));
+
+    let mut p = byte::TakeWhile1(|b: u8| b.is_ascii_digit()).with_span();
+    assert_eq!(
+        p.parse(b"x123y".as_ref(), 1),
+        Ok((4, (Span::new(1, 4), b"123".as_ref())))
+    );
+
+    let mut p = Parser::<&str>::with_span(ascii::TakeWhile1(|b: u8| b.is_ascii_digit()));
+    assert_eq!(p.parse("x123y", 1), Ok((4, (Span::new(1, 4), "123"))));
+}
+
+#[test]
+fn spanned() {
+    let input = "foo + barbaz";
+    let ident = str::TakeWhile1(|c: char| c.is_alphabetic()).spanned();
+    let mut p = (ident, " + ", ident).map(|(a, _, b)| a.merge(b, |a, b| (a, b)));
+    let (to, output) = p.parse(input, 0).unwrap();
+    assert_eq!(to, 12);
+    assert_eq!(output.span, Span::new(0, 12));
+    assert_eq!(output.value, ("foo", "barbaz"));
+    assert_eq!(output.span.slice(input), input);
+
+    let mut p = 'a'.repeat(..).fold(|| 0, |n, _| n + 1).spanned();
+    assert_eq!(p.parse("aab", 0), Ok((2, Spanned::new(Span::new(0, 2), 2))));
+    assert_eq!(p.parse("aab", 2), Ok((2, Spanned::new(Span::new(2, 2), 0))));
+}
@@ -28,6 +28,92 @@ fn str() {
     }
 }
 
+#[test]
+fn no_case() {
+    t! {
+        NoCase("select") => {
+            "" => Err((0, Error::NoCase("select"))),
+            "sel" => Err((0, Error::NoCase("select"))),
+            "select" => Ok((6, "select")),
+            "SELECT *" => Ok((6, "SELECT")),
+            "SeLeCt" => Ok((6, "SeLeCt")),
+            "selects" => Ok((6, "select")),
+            "insert" => Err((0, Error::NoCase("select"))),
+        },
+        NoCase("Πr²") => {
+            "πR²" => Ok((5, "πR²")),
+            "ΠR2" => Err((0, Error::NoCase("Πr²"))),
+        },
+        NoCase("straße") => {
+            "STRASSE" => Ok((7, "STRASSE")),
+            "Straße!" => Ok((7, "Straße")),
+            "strasse" => Ok((7, "strasse")),
+        },
+        NoCase("ss") => {
+            "ß" => Ok((2, "ß")),
+            "S" => Err((0, Error::NoCase("ss"))),
+        },
+        NoCase("s") => {
+            "ß" => Err((0, Error::NoCase("s"))),
+            "ſ" => Ok((2, "ſ")),
+        },
+        NoCase("kelvin") => {
+            "\u{212A}ELVIN" => Ok((8, "\u{212A}ELVIN")),
+        },
+        NoCase("") => {
+            "x" => Ok((0, "")),
+        },
+    }
+    assert_eq!(
+        Error::NoCase("select").to_string(),
+        "expected \"select\" (case-insensitive)"
+    );
+}
+
+#[test]
+fn one_of() {
+    use munch::class::Class;
+
+    let ident = Class::new().range('a'..='z').char('_').range('α'..='ω');
+    t! {
+        OneOf(&ident) => {
+            "" => Err((0, Error::OneOf(ident.clone()))),
+            "π" => Ok((2, 'π')),
+            "_x" => Ok((1, '_')),
+            "X" => Err((0, Error::OneOf(ident.clone()))),
+        },
+        NoneOf(&ident) => {
+            "" => Err((0, Error::NoneOf(ident.clone()))),
+            "π" => Err((0, Error::NoneOf(ident.clone()))),
+            "X" => Ok((1, 'X')),
+            "²" => Ok((2, '²')),
+        },
+    }
+
+    t! {
+        Capture((OneOf(&ident), TakeWhile(|ch| ident.contains(ch) || ch.is_ascii_digit()))) => {
+            "πr2 = 1" => Ok((4, "πr2")),
+            "2πr" => Err((0, Error::OneOf(ident.clone()))),
+        },
+        TakeWhileOneOf(&ident) => {
+            "" => Ok((0, "")),
+            "πr_2" => Ok((4, "πr_")),
+        },
+        TakeWhile1OneOf(&ident) => {
+            "πr_2" => Ok((4, "πr_")),
+            "2" => Err((0, Error::OneOf(ident.clone()))),
+        },
+    }
+    assert_eq!(
+        Error::OneOf(ident.clone()).to_string(),
+        "expected one of [_a-zα-ω]"
+    );
+    assert_eq!(
+        Error::NoneOf(ident.clone()).to_string(),
+        "expected none of [_a-zα-ω]"
+    );
+}
+
 #[test]
 fn satisfy() {
     t! {
@@ -65,6 +151,33 @@ fn take_while1() {
     }
 }
 
+#[test]
+fn take_until() {
+    t! {
+        TakeUntil('\n') => {
+            "" => Ok((0, "")),
+            "πr²\nh" => Ok((5, "πr²")),
+            "πr²" => Ok((5, "πr²")),
+        },
+        TakeUntil('²') => {
+            "πr²" => Ok((3, "πr")),
+            "πr2" => Ok((4, "πr2")),
+        },
+        TakeUntil("*/") => {
+            "π * r² */ h" => Ok((9, "π * r² ")),
+            "π * r²" => Ok((8, "π * r²")),
+        },
+        TakeUntilAny(['\n', ';']) => {
+            "key = πr² ; comment" => Ok((12, "key = πr² ")),
+            "key = πr²\n" => Ok((11, "key = πr²")),
+        },
+        TakeUntilAny(['²', ';']) => {
+            "key = πr² ; comment" => Ok((9, "key = πr")),
+            "π;" => Ok((2, "π")),
+        },
+    }
+}
+
 #[test]
 fn capture() {
     t! {
@@ -79,6 +192,19 @@ fn capture() {
     }
 }
 
+#[test]
+fn skip_until() {
+    t! {
+        SkipUntil(Satisfy(|ch| ch == 'r' || ch == '²')) => {
+            "" => Ok((0, "")),
+            "π" => Ok((2, "π")),
+            "πr" => Ok((2, "π")),
+            "π²r" => Ok((2, "π")),
+            "r" => Ok((0, "")),
+        },
+    }
+}
+
 #[test]
 fn any() {
     t! {
@@ -0,0 +1,124 @@
+use munch::error::{Error, Shown};
+use munch::span::Span;
+use munch::token::*;
+use munch::{select, Parser};
+
+#[derive(Clone, Debug, PartialEq)]
+enum Tok {
+    Number(i64),
+    Ident(String),
+    Plus,
+    Open,
+    Close,
+}
+
+fn token_error<'a>(expected: Option<&'a Tok>, found: Option<&'a Tok>) -> Error<'a> {
+    let shown = |token: &'a Tok| Shown::Debug(token);
+    Error::Token(expected.map(shown), found.map(shown))
+}
+
+#[test]
+fn token() {
+    use Tok::*;
+
+    let tokens = [Open, Number(1), Plus, Ident("x".into()), Close];
+    let tokens = tokens.as_ref();
+
+    // Errors refer to the tokens themselves, which are only formatted when displayed.
+    let open = &Open;
+    assert_eq!(Token(open).parse(tokens, 0), Ok((1, &Open)));
+    assert_eq!(
+        Token(open).parse(tokens, 1),
+        Err((1, token_error(Some(open), Some(&tokens[1]))))
+    );
+    assert_eq!(
+        Token(open).parse(tokens, 5),
+        Err((5, token_error(Some(open), None)))
+    );
+
+    let mut ident = Satisfy(|token: &Tok| matches!(token, Ident(_)));
+    assert_eq!(ident.parse(tokens, 3), Ok((4, &Ident("x".into()))));
+    assert_eq!(ident.parse(tokens, 2), Err((2, Error::Satisfy)));
+
+    let mut number = Select(|token: &Tok| match token {
+        Number(n) => Some(*n),
+        _ => None,
+    });
+    assert_eq!(number.parse(tokens, 1), Ok((2, 1)));
+    assert_eq!(
+        number.parse(tokens, 2),
+        Err((2, token_error(None, Some(&tokens[2]))))
+    );
+
+    let mut name = select! {
+        Ident(name) if name != "_" => name.clone(),
+    };
+    assert_eq!(name.parse(tokens, 3), Ok((4, "x".to_owned())));
+    let underscore = [Ident("_".into())];
+    assert_eq!(
+        name.parse(&underscore, 0),
+        Err((0, token_error(None, Some(&underscore[0]))))
+    );
+    let error = name.parse(&underscore, 0).unwrap_err().1;
+    assert_eq!(
+        error.to_string(),
+        "expected a matching token, found Ident(\"_\")"
+    );
+
+    let error = Token(&Open)
+        .alt(Token(&Plus))
+        .parse(tokens, 4)
+        .unwrap_err()
+        .1;
+    assert_eq!(error.to_string(), "expected Open or Plus, found Close");
+    let error = Token(&Open).parse(tokens, 5).unwrap_err().1;
+    assert_eq!(error.to_string(), "expected Open, found end of input");
+
+    let mut open_any = (Token(&Open), Any, select!(Plus => ()));
+    assert_eq!(open_any.parse(tokens, 0), Ok((3, (&Open, &Number(1), ()))));
+
+    assert_eq!(Any.parse(tokens, 4), Ok((5, &Close)));
+    assert_eq!(Any.parse(tokens, 5), Err((5, Error::Any)));
+    assert_eq!(End.parse(tokens, 5), Ok((5, ())));
+    assert_eq!(End.parse(tokens, 4), Err((4, Error::End)));
+}
+
+#[test]
+fn spans() {
+    use Tok::*;
+
+    // "(1 + x" as lexed by a separate lexer.
+    let tokens = [
+        (Span::new(0, 1), Open),
+        (Span::new(1, 2), Number(1)),
+        (Span::new(3, 4), Plus),
+        (Span::new(5, 6), Ident("x".into())),
+    ];
+    let tokens = tokens.as_ref();
+
+    let mut atom = select! {
+        (_, Number(n)) => *n,
+        (_, Ident(name)) if name == "x" => 42,
+    };
+    let mut sum = (
+        select!((_, Open) => ()),
+        atom,
+        select!((_, Plus) => ()),
+        atom,
+        select!((_, Close) => ()),
+    )
+        .map(|(_, a, _, b, _)| a + b);
+
+    assert_eq!(atom.parse(tokens, 3), Ok((4, 42)));
+    let error = sum.parse(tokens, 0).unwrap_err();
+    assert_eq!(error, (4, token_error(None, None)));
+    assert_eq!(
+        locate(tokens, error),
+        (Span::new(6, 6), token_error(None, None))
+    );
+    assert_eq!(locate(tokens, (2, ())), (Span::new(3, 4), ()));
+    assert_eq!(
+        locate(&[] as &[(Span, Tok)], (0, ())),
+        (Span::new(0, 0), ())
+    );
+}
@@ -0,0 +1,123 @@
+use std::io::{BufReader, ErrorKind};
+
+use munch::error::Error;
+use munch::partial::byte::{BigEndian, Byte, Take};
+use munch::partial::str::*;
+use munch::partial::{Partial, ReadError, Try};
+use munch::window::*;
+use munch::Parser;
+
+#[test]
+fn lines() {
+    let mut line = TakeWhile(|ch| ch != '\n').map(str::to_owned).p() << Char('\n');
+
+    let chunks = ["πr", "²\n\nfi", "rst", "\nsecond\n", "third"];
+    let mut window = Window::new(Chunks::new(chunks.iter()));
+    assert_eq!(window.parse_str(&mut line).unwrap(), Some("πr²".to_owned()));
+    assert_eq!(window.parse_str(&mut line).unwrap(), Some("".to_owned()));
+    assert_eq!(
+        window.parse_str(&mut line).unwrap(),
+        Some("first".to_owned())
+    );
+    assert_eq!(
+        window.parse_str(&mut line).unwrap(),
+        Some("second".to_owned())
+    );
+    assert_eq!(window.offset(), 20);
+    match window.parse_str(&mut line) {
+        Err(ReadError::Parse(25, Error::Char('\n'))) => {}
+        otherwise => panic!("{:?}", otherwise),
+    }
+}
+
+#[test]
+fn split_char() {
+    // "π" is split across chunks, so the first window can't be read as a `str` in full.
+    let chunks = [b"a\xCF".as_ref(), b"\x80b"];
+    let mut any = Any;
+    let mut window = Window::new(Chunks::new(chunks.iter()));
+    assert_eq!(window.parse_str(&mut any).unwrap(), Some('a'));
+    assert_eq!(window.parse_str(&mut any).unwrap(), Some('π'));
+    assert_eq!(window.parse_str(&mut any).unwrap(), Some('b'));
+    assert_eq!(window.parse_str(&mut any).unwrap(), None);
+
+    let chunks = [b"a\xFF".as_ref()];
+    let mut window = Window::new(Chunks::new(chunks.iter()));
+    match window.parse_str(&mut any) {
+        Err(ReadError::Io(error)) => assert_eq!(error.kind(), ErrorKind::InvalidData),
+        otherwise => panic!("{:?}", otherwise),
+    }
+}
+
+#[test]
+fn retry() {
+    let mut p = (Str("abcd").p() | Str("ab")).map(str::to_owned);
+
+    let mut window = Window::new(Chunks::new(["ab", "cd"]));
+    assert_eq!(window.parse_str(&mut p).unwrap(), Some("abcd".to_owned()));
+    assert_eq!(window.parse_str(&mut p).unwrap(), None);
+
+    let mut window = Window::new(Chunks::new(["ab"]));
+    assert_eq!(window.parse_str(&mut p).unwrap(), Some("ab".to_owned()));
+    assert_eq!(window.parse_str(&mut p).unwrap(), None);
+}
+
+#[test]
+fn long_item() {
+    use munch::partial::byte::TakeWhile;
+
+    // Only the end of the stream settles a run that fills every window.
+    let mut input = vec![b'a'; 3000];
+    input.push(b';');
+    fn len((a, _): (&[u8], u8)) -> usize {
+        a.len()
+    }
+    let p = || {
+        Try((TakeWhile(|b| b == b'a'), Byte(b';')))
+            .map(len)
+            .optional()
+    };
+
+    assert_eq!(
+        p().parse(Partial::complete(&input[..]), 0),
+        Ok((3001, Some(3000)))
+    );
+    let mut p = p();
+    let mut window = Window::new(Chunks::new(input.chunks(700)));
+    assert_eq!(window.parse(&mut p).unwrap(), Some(Some(3000)));
+    assert_eq!(window.parse(&mut p).unwrap(), None);
+    assert_eq!(window.offset(), 3001);
+}
+
+#[test]
+fn bytes() {
+    let mut frame = BigEndian::u16
+        .bind(|len| Take(len as usize))
+        .map(<[u8]>::to_vec);
+
+    let input = b"\x00\x03abc\x00\x00\x00\x05hello".as_ref();
+    let mut window = Window::new(BufReader::with_capacity(1, input));
+    assert_eq!(window.parse(&mut frame).unwrap(), Some(b"abc".to_vec()));
+    assert_eq!(window.parse(&mut frame).unwrap(), Some(b"".to_vec()));
+    assert_eq!(window.parse(&mut frame).unwrap(), Some(b"hello".to_vec()));
+    assert_eq!(window.parse(&mut frame).unwrap(), None);
+    assert_eq!(window.offset(), 14);
+}
+
+#[test]
+fn borrowed() {
+    let mut word = TakeWhile1(char::is_alphabetic).p() << TakeWhile(char::is_whitespace);
+
+    let chunks = ["hel", "lo wo", "rld"];
+    let mut window = Window::new(Chunks::new(chunks.iter()));
+    assert_eq!(window.parse_str(&mut word).unwrap(), Some("hello"));
+    assert_eq!(window.parse_str(&mut word).unwrap(), Some("world"));
+    assert_eq!(window.parse_str(&mut word).unwrap(), None);
+
+    let mut frame = BigEndian::u8.bind(|len| Take(len as usize));
+    let input = b"\x02ab\x03cde".as_ref();
+    let mut window = Window::new(BufReader::with_capacity(1, input));
+    assert_eq!(window.parse(&mut frame).unwrap(), Some(b"ab".as_ref()));
+    assert_eq!(window.parse(&mut frame).unwrap(), Some(b"cde".as_ref()));
+    assert_eq!(window.parse(&mut frame).unwrap(), None);
+}